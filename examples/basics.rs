@@ -1,5 +1,5 @@
 extern crate tylar;
-use tylar::{NumType, Add, Sub, Mul, Div, P1, P2, P3, P5, N1, N2, N3, N4};
+use tylar::{NumType, Add, Sub, Mul, Div, Sum, Diff, Prod, P1, P2, P3, P5, N1, N2, N3, N4};
 
 // Type-level function, calculating (N * (-4) + 2) / 2
 trait Calculation1 { type Out; }
@@ -9,6 +9,9 @@ impl<N: NumType, Times4, Plus2> Calculation1 for N where N: Mul<N4, Out=Times4>,
 trait Calculation2 { type Out; }
 impl<N: NumType, OnePlusN, OneMinusN: NumType> Calculation2 for N where P1: Sub<N, Out=OneMinusN>, P1: Add<N, Out=OnePlusN>, OnePlusN: Mul<OneMinusN> { type Out = OnePlusN::Out; }
 
+// The same calculation, written with the type aliases for the operations
+type Calculation2Alias<N> = Prod<Sum<P1, N>, Diff<P1, N>>;
+
 fn main() {
     // Unfortunately these first 5 examples don't work in Rust 1.0
     let result1: i32 = <N2 as Add<P5>>::Out::new().into();
@@ -25,8 +28,11 @@ fn main() {
     
     let result5: i32 = <N1 as Calculation2>::Out::new().into();
     println!("(1 + N) * (1 - N) = {} (for N = -1)", result5);
+
+    let result6: i32 = Calculation2Alias::<P3>::new().into();
+    println!("(1 + N) * (1 - N) = {} (for N = 3, using aliases)", result6);
     
-    fn do_something<A: NumType, B: NumType>() where A: Calculation2<Out=B> {
+    fn do_something<A, B: NumType>() where A: NumType + Calculation2<Out=B> {
         // This function only exists for combinations of A and B where B = (1 + A) * (1 - A)
         let a: i32 = A::new().into();
         let b: i32 = B::new().into();
@@ -16,9 +16,9 @@ struct TVec<T, N: PosType> {
 
 impl<T, N: PosType + NumType> Debug for TVec<T, N> where T: Debug {
     fn fmt(&self, formatter: &mut Formatter) -> Result {
-        try!(self.vec.fmt(formatter));
+        self.vec.fmt(formatter)?;
         let len: usize = N::new().into();
-        try!(write!(formatter, " ({})", len));
+        write!(formatter, " ({})", len)?;
         Ok(())
     }
 }
@@ -33,7 +33,7 @@ impl<T, N: PosType> TVec<T, N> {
     pub fn push(self, v: T) ->  TVec<T, Succ<N>> {
         let mut vec = self.vec;
         vec.push(v);
-        TVec {vec: vec, p: PhantomData}
+        TVec {vec, p: PhantomData}
     }
 }
 
@@ -43,21 +43,20 @@ impl<T, N: PosType + NumType> TVec<T, N> {
         let mut vec = self.vec;
         let mut other = other;
         vec.append(&mut other.vec);
-        TVec {vec: vec, p: PhantomData}
+        TVec {vec, p: PhantomData}
     }
 }
 
 fn main() {
     for i in 0..2 {
         let v = TVec::new();
-        let r;
-        if i % 2 == 0 {
+        let r = if i % 2 == 0 {
             let v = v.push(1);
-            r = v.push(2);
+            v.push(2)
         } else {
             let v = v.push(3);
-            r = v.push(4);
-        }
+            v.push(4)
+        };
         println!("{:?}", r);
     }
 }
\ No newline at end of file
@@ -0,0 +1,47 @@
+//! Type aliases for the results of the operations, e.g. `Sum<A, B>` for `<A as Add<B>>::Out`.
+//!
+//! These are re-exported at the crate root and make signatures and where-clauses much
+//! shorter:
+//!
+//! ```
+//! #[macro_use] extern crate tylar;
+//! use tylar::{Sum, Prod, Quot, P2, P3, N4, N5};
+//!
+//! // (N * (-4) + 2) / 2
+//! type Calculation<N> = Quot<Sum<Prod<N, N4>, P2>, P2>;
+//!
+//! # fn main() {
+//! type_eq!(Calculation<P3>, N5);
+//! # }
+//! ```
+
+use super::{Add, Sub, Mul, Div, Rem, Neg, Abs, Halve, Double, Triple, Quadruple, Shl, Shr, Compare};
+
+/// The sum of `A` and `B`.
+pub type Sum<A, B> = <A as Add<B>>::Out;
+/// The difference of `A` and `B`.
+pub type Diff<A, B> = <A as Sub<B>>::Out;
+/// The product of `A` and `B`.
+pub type Prod<A, B> = <A as Mul<B>>::Out;
+/// The quotient of `A` and `B`, rounded towards zero.
+pub type Quot<A, B> = <A as Div<B>>::Out;
+/// The remainder of `A` divided by `B`, with the sign of `A`.
+pub type Mod<A, B> = <A as Rem<B>>::Out;
+/// The negation of `A`.
+pub type Negation<A> = <A as Neg>::Out;
+/// The absolute value of `A`.
+pub type AbsVal<A> = <A as Abs>::Out;
+/// Half of the even number `A`.
+pub type Halved<A> = <A as Halve>::Out;
+/// Twice `A`.
+pub type Doubled<A> = <A as Double>::Out;
+/// Three times `A`.
+pub type Tripled<A> = <A as Triple>::Out;
+/// Four times `A`.
+pub type Quadrupled<A> = <A as Quadruple>::Out;
+/// `A` shifted left by `E` bits, i.e. `A` * 2^`E`.
+pub type Shleft<A, E> = <A as Shl<E>>::Out;
+/// `A` shifted right by `E` bits, i.e. `A` / 2^`E`, rounded towards negative infinity.
+pub type Shright<A, E> = <A as Shr<E>>::Out;
+/// The ordering of `A` and `B`, i.e. `Less`, `Equal` or `Greater`.
+pub type Cmp<A, B> = <A as Compare<B>>::Out;
@@ -0,0 +1,94 @@
+//! Compile-time assertions about number types, which fail at the assertion itself instead
+//! of somewhere downstream.
+//!
+//! The macros `assert_num_eq!`, `assert_pos!` and `assert_lt!` expand to constant items, so
+//! they can be used at module scope and inside functions:
+//!
+//! ```
+//! #[macro_use] extern crate tylar;
+//! use tylar::{Sum, P3, P4, P7};
+//!
+//! assert_num_eq!(Sum<P3, P4>, P7);
+//! assert_lt!(P3, P4);
+//!
+//! fn main() {
+//!     assert_pos!(P7);
+//! }
+//! ```
+//!
+//! Constant items can't refer to the generic parameters of an enclosing function, so in
+//! generic impls the assertions need a name, which makes them associated constants of the
+//! impl. Inside generic functions, call the functions of this module instead. Either way,
+//! this checks that the bounds in scope imply the assertion:
+//!
+//! ```
+//! #[macro_use] extern crate tylar;
+//! use tylar::{NumType, Abs, N3};
+//!
+//! struct Offset<N>(N);
+//!
+//! impl<N: Abs> Offset<N> {
+//!     assert_pos!(MAGNITUDE_IS_POSITIVE: <N as Abs>::Out);
+//! }
+//!
+//! fn distance<N: Abs>() -> i64 {
+//!     tylar::asserts::pos::<<N as Abs>::Out>();
+//!     <N as Abs>::Out::VALUE
+//! }
+//! # fn main() { assert_eq!(distance::<N3>(), 3); }
+//! ```
+//!
+//! A failing assertion is reported with the unsatisfied bound at the assertion:
+//!
+//! ```compile_fail
+//! #[macro_use] extern crate tylar;
+//! use tylar::{Sum, P3, P4, P8};
+//!
+//! // error[E0277]: the trait bound `Succ<...>: Same<Succ<...>>` is not satisfied
+//! //   --> src/main.rs:11:16
+//! //    |
+//! // 11 | assert_num_eq!(Sum<P3, P4>, P8);
+//! //    |                ^^^^^^^^^^^ unsatisfied trait bound
+//! //    |
+//! // note: required by a bound in `num_eq`
+//! assert_num_eq!(Sum<P3, P4>, P8);
+//! # fn main() {}
+//! ```
+
+use super::{NumType, PosType, Same, Compare, Less};
+
+/// Checks that `A` and `B` are the same number, used to implement `assert_num_eq!`.
+#[inline(always)]
+pub const fn num_eq<A: NumType + Same<B>, B: NumType>() {}
+
+/// Checks that `N` is positive (or zero), used to implement `assert_pos!`.
+#[inline(always)]
+pub const fn pos<N: PosType>() {}
+
+/// Checks that `A` is less than `B`, used to implement `assert_lt!`.
+#[inline(always)]
+pub const fn lt<A: NumType + Compare<B, Out=Less>, B: NumType>() {}
+
+/// Asserts at compile time that two number types are the same, e.g.
+/// `assert_num_eq!(Sum<P3, P4>, P7);` (see `asserts`).
+#[macro_export]
+macro_rules! assert_num_eq {
+    ($name:ident: $a:ty, $b:ty) => { #[allow(dead_code)] const $name: () = $crate::asserts::num_eq::<$a, $b>(); };
+    ($a:ty, $b:ty) => { const _: () = $crate::asserts::num_eq::<$a, $b>(); };
+}
+
+/// Asserts at compile time that a number type is positive (or zero), e.g.
+/// `assert_pos!(P7);` (see `asserts`).
+#[macro_export]
+macro_rules! assert_pos {
+    ($name:ident: $n:ty) => { #[allow(dead_code)] const $name: () = $crate::asserts::pos::<$n>(); };
+    ($n:ty) => { const _: () = $crate::asserts::pos::<$n>(); };
+}
+
+/// Asserts at compile time that a number type is less than another one, e.g.
+/// `assert_lt!(P3, P4);` (see `asserts`).
+#[macro_export]
+macro_rules! assert_lt {
+    ($name:ident: $a:ty, $b:ty) => { #[allow(dead_code)] const $name: () = $crate::asserts::lt::<$a, $b>(); };
+    ($a:ty, $b:ty) => { const _: () = $crate::asserts::lt::<$a, $b>(); };
+}
@@ -0,0 +1,67 @@
+//! Addition that signals at the type level whether the result exceeds a bound, instead of
+//! rejecting it like `BoundedAdd`.
+//!
+//! The results are `Ok<N>` and `Overflow`, which are not exported at the crate root, so that
+//! `Ok` doesn't shadow `Result::Ok` for code that imports `tylar::*`.
+
+use core::marker::PhantomData;
+
+use super::{private, NumType, BoolType, True, False, Add, Compare, OrderingType, Less, Equal, Greater};
+
+/// Marker trait for the results of checked operations, i.e. `Ok<N>` and `Overflow`.
+/// This trait is sealed.
+pub trait CheckedType: private::Sealed {
+    /// Whether the operation succeeded, as a type-level boolean (for use with `If`).
+    type IsOk: BoolType;
+}
+
+/// Result of a checked operation that stayed in range, carrying the result `N`.
+#[allow(dead_code)]
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct Ok<N> {
+    phantom: PhantomData<N>
+}
+
+/// Result of a checked operation that went out of range.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct Overflow;
+
+impl<N> private::Sealed for Ok<N> {}
+impl private::Sealed for Overflow {}
+impl<N: NumType> CheckedType for Ok<N> { type IsOk = True; }
+impl CheckedType for Overflow { type IsOk = False; }
+
+/// Addition of number types which signals at the type level whether the sum exceeds `Max`.
+///
+/// Unlike `BoundedAdd`, this doesn't reject out-of-range sums, but yields `Ok<Sum>` if
+/// `Sum` ≤ `Max` and `Overflow` otherwise, so the result can be used for branching:
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::checked::{CheckedAdd, CheckedType, Ok, Overflow};
+/// use tylar::{If, P4, P5, P6, P9};
+///
+/// # fn main() {
+/// type_eq!(<P4 as CheckedAdd<P5, P9>>::Out, Ok<P9>);
+/// type_eq!(<P4 as CheckedAdd<P6, P9>>::Out, Overflow);
+///
+/// // pick a wider storage type if the sum doesn't fit
+/// type Fits = <<P4 as CheckedAdd<P6, P9>>::Out as CheckedType>::IsOk;
+/// type_eq!(<Fits as If<u8, u16>>::Out, u16);
+/// # }
+/// ```
+pub trait CheckedAdd<RHS, Max: NumType>: NumType {
+    /// Result of the operation, i.e. `Ok<Self + RHS>` or `Overflow`.
+    type Out: CheckedType;
+}
+impl<A, RHS, Max: NumType, S, O> CheckedAdd<RHS, Max> for A
+    where A: Add<RHS, Out=S>, S: Compare<Max, Out=O>, O: OrderingToChecked<S> { type Out = O::Out; }
+
+/// Maps the ordering of a sum relative to the bound to the result of `CheckedAdd`.
+#[doc(hidden)]
+pub trait OrderingToChecked<N>: OrderingType {
+    type Out: CheckedType;
+}
+impl<N: NumType> OrderingToChecked<N> for Less { type Out = Ok<N>; }
+impl<N: NumType> OrderingToChecked<N> for Equal { type Out = Ok<N>; }
+impl<N: NumType> OrderingToChecked<N> for Greater { type Out = Overflow; }
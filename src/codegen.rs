@@ -0,0 +1,60 @@
+//! Helpers for code generators, like procedural macros and build scripts, that need to name
+//! the number type for a computed integer (with the `codegen` feature).
+//!
+//! ```
+//! use tylar::codegen::type_path;
+//!
+//! assert_eq!(type_path(3).unwrap(), "::tylar::P3");
+//! assert_eq!(type_path(-12).unwrap(), "::tylar::Minus10<::tylar::N2>");
+//! assert_eq!(type_path(113).unwrap(), "::tylar::Plus100<::tylar::Plus10<::tylar::P3>>");
+//! assert!(type_path(500).is_err());
+//! ```
+
+use std::fmt;
+
+use super::MAX_PRACTICAL_DEPTH;
+
+/// Error for a value whose magnitude exceeds `MAX_PRACTICAL_DEPTH`, so that the generated
+/// type would run into the default recursion limit.
+#[derive(Copy,Clone,PartialEq,Eq,Debug)]
+pub struct TooLarge(pub i64);
+
+impl fmt::Display for TooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the magnitude of {} exceeds MAX_PRACTICAL_DEPTH ({})", self.0, MAX_PRACTICAL_DEPTH)
+    }
+}
+
+impl std::error::Error for TooLarge {}
+
+/// Returns the path of the number type with the value `n`, built from the blocks `Plus100`,
+/// `Plus50`, `Plus10` and `P1`..`P9` (or `Minus100` to `N9` for negative numbers), like `n!`.
+/// The path starts with `::tylar::`, so it works in any module of the generated code.
+pub fn type_path(n: i64) -> Result<String, TooLarge> {
+    if n.unsigned_abs() > MAX_PRACTICAL_DEPTH as u64 {
+        return Err(TooLarge(n));
+    }
+    let (block, digit) = if n < 0 { ("Minus", 'N') } else { ("Plus", 'P') };
+    let mut rest = n.unsigned_abs();
+    let mut prefix = String::new();
+    let mut depth = 0;
+    for &step in &[100, 50, 10] {
+        while rest >= step {
+            prefix.push_str(&format!("::tylar::{}{}<", block, step));
+            depth += 1;
+            rest -= step;
+        }
+    }
+    let base = match rest {
+        0 => String::from("Zero"),
+        r => format!("{}{}", digit, r),
+    };
+    Ok(format!("{}::tylar::{}{}", prefix, base, ">".repeat(depth)))
+}
+
+/// Returns the tokens of the number type with the value `n`, see `type_path` (with the
+/// `proc-macro2` feature).
+#[cfg(feature = "proc-macro2")]
+pub fn type_tokens(n: i64) -> Result<proc_macro2::TokenStream, TooLarge> {
+    Ok(type_path(n)?.parse().expect("number type paths are valid tokens"))
+}
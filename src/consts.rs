@@ -0,0 +1,86 @@
+//! Shorthands for the numbers from 10 to 64 and from –10 to –64, e.g. `P16` and `N42`.
+//!
+//! These are re-exported at the crate root, like `P1`..`P9` and `N1`..`N9`, so that crates
+//! which need these numbers can use the same names instead of defining their own. New
+//! shorthands should be added here.
+//!
+//! ```
+//! use tylar::{NumType, P32, N42};
+//!
+//! assert_eq!(P32::VALUE, 32);
+//! assert_eq!(N42::VALUE, -42);
+//! ```
+
+use super::{Zero, Plus10, Plus50, Minus10, Minus50};
+use super::{P1, P2, P3, P4, P5, P6, P7, P8, P9, N1, N2, N3, N4, N5, N6, N7, N8, N9};
+
+// Defines the shorthands for a number and its negation, each in terms of a building block
+// for 10 or 50 and a smaller number, so that no alias is nested deeply.
+macro_rules! consts {
+    ($($v:literal: $p:ident = $pty:ty, $n:ident = $nty:ty;)*) => {
+        $(
+            #[doc = concat!("Shorthand for the number ", stringify!($v), ".")]
+            pub type $p = $pty;
+            #[doc = concat!("Shorthand for the number –", stringify!($v), ".")]
+            pub type $n = $nty;
+        )*
+    }
+}
+
+consts! {
+    10: P10 = Plus10<Zero>, N10 = Minus10<Zero>;
+    11: P11 = Plus10<P1>, N11 = Minus10<N1>;
+    12: P12 = Plus10<P2>, N12 = Minus10<N2>;
+    13: P13 = Plus10<P3>, N13 = Minus10<N3>;
+    14: P14 = Plus10<P4>, N14 = Minus10<N4>;
+    15: P15 = Plus10<P5>, N15 = Minus10<N5>;
+    16: P16 = Plus10<P6>, N16 = Minus10<N6>;
+    17: P17 = Plus10<P7>, N17 = Minus10<N7>;
+    18: P18 = Plus10<P8>, N18 = Minus10<N8>;
+    19: P19 = Plus10<P9>, N19 = Minus10<N9>;
+    20: P20 = Plus10<P10>, N20 = Minus10<N10>;
+    21: P21 = Plus10<P11>, N21 = Minus10<N11>;
+    22: P22 = Plus10<P12>, N22 = Minus10<N12>;
+    23: P23 = Plus10<P13>, N23 = Minus10<N13>;
+    24: P24 = Plus10<P14>, N24 = Minus10<N14>;
+    25: P25 = Plus10<P15>, N25 = Minus10<N15>;
+    26: P26 = Plus10<P16>, N26 = Minus10<N16>;
+    27: P27 = Plus10<P17>, N27 = Minus10<N17>;
+    28: P28 = Plus10<P18>, N28 = Minus10<N18>;
+    29: P29 = Plus10<P19>, N29 = Minus10<N19>;
+    30: P30 = Plus10<P20>, N30 = Minus10<N20>;
+    31: P31 = Plus10<P21>, N31 = Minus10<N21>;
+    32: P32 = Plus10<P22>, N32 = Minus10<N22>;
+    33: P33 = Plus10<P23>, N33 = Minus10<N23>;
+    34: P34 = Plus10<P24>, N34 = Minus10<N24>;
+    35: P35 = Plus10<P25>, N35 = Minus10<N25>;
+    36: P36 = Plus10<P26>, N36 = Minus10<N26>;
+    37: P37 = Plus10<P27>, N37 = Minus10<N27>;
+    38: P38 = Plus10<P28>, N38 = Minus10<N28>;
+    39: P39 = Plus10<P29>, N39 = Minus10<N29>;
+    40: P40 = Plus10<P30>, N40 = Minus10<N30>;
+    41: P41 = Plus10<P31>, N41 = Minus10<N31>;
+    42: P42 = Plus10<P32>, N42 = Minus10<N32>;
+    43: P43 = Plus10<P33>, N43 = Minus10<N33>;
+    44: P44 = Plus10<P34>, N44 = Minus10<N34>;
+    45: P45 = Plus10<P35>, N45 = Minus10<N35>;
+    46: P46 = Plus10<P36>, N46 = Minus10<N36>;
+    47: P47 = Plus10<P37>, N47 = Minus10<N37>;
+    48: P48 = Plus10<P38>, N48 = Minus10<N38>;
+    49: P49 = Plus10<P39>, N49 = Minus10<N39>;
+    50: P50 = Plus50<Zero>, N50 = Minus50<Zero>;
+    51: P51 = Plus50<P1>, N51 = Minus50<N1>;
+    52: P52 = Plus50<P2>, N52 = Minus50<N2>;
+    53: P53 = Plus50<P3>, N53 = Minus50<N3>;
+    54: P54 = Plus50<P4>, N54 = Minus50<N4>;
+    55: P55 = Plus50<P5>, N55 = Minus50<N5>;
+    56: P56 = Plus50<P6>, N56 = Minus50<N6>;
+    57: P57 = Plus50<P7>, N57 = Minus50<N7>;
+    58: P58 = Plus50<P8>, N58 = Minus50<N8>;
+    59: P59 = Plus50<P9>, N59 = Minus50<N9>;
+    60: P60 = Plus50<P10>, N60 = Minus50<N10>;
+    61: P61 = Plus50<P11>, N61 = Minus50<N11>;
+    62: P62 = Plus50<P12>, N62 = Minus50<N12>;
+    63: P63 = Plus50<P13>, N63 = Minus50<N13>;
+    64: P64 = Plus50<P14>, N64 = Minus50<N14>;
+}
@@ -1,21 +1,341 @@
 //! Type-Level Arithmetic in Rust (tylar).
+//!
+//! # Limits
+//!
+//! Numbers are unary (Peano) types, so the typechecker needs one level of recursion per
+//! unit of magnitude just to prove that a type is a `NumType`. The magnitude of every
+//! number in a computation, including the intermediate results of the operations, must
+//! therefore stay below the `recursion_limit` of the crate that uses it. With the default
+//! limit of 128, numbers up to `MAX_PRACTICAL_DEPTH` (120, see also `PMax`) can be used.
+//! Larger numbers need a higher limit, e.g. `#![recursion_limit = "256"]`, which is what
+//! this crate is tested with, and which allows values and intermediate results up to
+//! about 250 for all operations.
+//! Only `Div` goes beyond its operands, because it doubles the divisor until it
+//! exceeds the dividend, which may take up to twice the magnitude of the dividend.
+//!
+//! Numbers up to 64 have shorthands, from `P1` to `P64` and `N1` to `N64` (see `consts`).
+//! To write down larger numbers, the building blocks `Plus5`, `Plus10`, `Plus50` and
+//! `Plus100` (and `Minus5` to `Minus100` for negative numbers) can be composed, e.g.
+//! `Plus100<Plus100<P3>>` for 203.
+//!
+//! # Features
+//!
+//! * `std` (enabled by default, implies `alloc`): the `std::error::Error` impl of
+//!   `OutOfRange`. Without it, the crate is `no_std`.
+//! * `alloc`: the `Vec`-based containers `stack::TStack` and `ring::RingBuf`, for `no_std`
+//!   targets with an allocator.
+//! * `serde`: implements `Serialize` and `Deserialize` for the number types, which are
+//!   represented by their integer value. Deserialization fails for any other value.
+//! * `typenum`: conversions from and to the number types of the `typenum` crate, see
+//!   `ToTypenum`, `ToUnsigned` and `FromTypenum`.
+//! * `generic-array` (implies `typenum`): conversions between `stack::TStack` and the
+//!   `GenericArray` of the `generic-array` crate, with the length given by `ArrayLen` (or
+//!   `ToArrayLength` in generic code).
+//! * `num-traits`: implements `ToPrimitive` of the `num-traits` crate for the number types,
+//!   `num_traits::Zero` for `Zero` and `One` for `P1`. Since arithmetic on number types
+//!   changes the type, `Num` and `Signed` are implemented for `AnyNum` instead.
+//! * `codegen` (implies `std`): `codegen::type_path` to name the number type for an integer
+//!   in generated code.
+//! * `proc-macro2` (implies `codegen`): `codegen::type_tokens`, which returns the tokens of
+//!   the number type for procedural macros.
+//! * `macros`: the procedural macro `n!`, which writes any integer literal (up to
+//!   `MAX_PRACTICAL_DEPTH`) as a number type, e.g. `n!(-1_00)`, and `for_range!`, which
+//!   repeats items for a range of integers, e.g. to implement a trait for number types.
+//!   Also `#[derive(VariantCount)]` for enums, see `VariantCount`.
+//! * `test-support` (implies `macros`): `for_each_tynum!`, which runs a block for every number
+//!   type in a range.
 
-use std::marker::PhantomData;
+#![recursion_limit = "256"]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(any(feature = "std", test))]
+extern crate core;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::num::{NonZeroI64, NonZeroU64};
+
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "typenum")]
+extern crate typenum;
+#[cfg(feature = "generic-array")]
+extern crate generic_array;
+#[cfg(feature = "macros")]
+extern crate tylar_macros;
+#[cfg(feature = "num-traits")]
+extern crate num_traits;
+#[cfg(feature = "proc-macro2")]
+extern crate proc_macro2;
+
+pub mod aliases;
+#[macro_use]
+pub mod asserts;
+pub mod checked;
+pub mod consts;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+pub mod list;
+pub mod rational;
+#[cfg(feature = "alloc")]
+pub mod ring;
+#[cfg(feature = "alloc")]
+pub mod stack;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "typenum")]
+mod typenum_impls;
+#[cfg(feature = "num-traits")]
+mod num_traits_impls;
+pub use aliases::*;
+pub use consts::*;
+#[cfg(feature = "typenum")]
+pub use typenum_impls::{ToTypenum, ToUnsigned, FromTypenum};
+#[cfg(feature = "macros")]
+pub use tylar_macros::{n, for_range, VariantCount};
+
+/// The length of a `GenericArray` (from the `generic-array` crate) with `N` elements.
+/// See `TStack::into_array()` and `TStack::from_array()` for conversions.
+#[cfg(feature = "generic-array")]
+pub type ArrayLen<N> = <N as ToUnsigned>::Unsigned;
+
+/// Number types that can be the length of a `GenericArray` (from the `generic-array` crate),
+/// which is `Length` (the same as `ArrayLen<Self>`). This is implemented for all positive
+/// numbers, and allows to use a number type as the length in generic code, with a single
+/// bound instead of a bound on the typenum type:
+///
+/// ```
+/// # extern crate generic_array;
+/// # extern crate tylar;
+/// use generic_array::GenericArray;
+/// use tylar::{ToArrayLength, P4};
+///
+/// fn zeros<N: ToArrayLength>() -> GenericArray<u8, N::Length> {
+///     GenericArray::default()
+/// }
+///
+/// # fn main() {
+/// assert_eq!(zeros::<P4>().len(), 4);
+/// # }
+/// ```
+#[cfg(feature = "generic-array")]
+pub trait ToArrayLength: ToUnsigned {
+    /// The length of a `GenericArray` with `Self` elements.
+    type Length: generic_array::ArrayLength;
+}
+#[cfg(feature = "generic-array")]
+impl<N: ToUnsigned> ToArrayLength for N where N::Unsigned: generic_array::ArrayLength {
+    type Length = N::Unsigned;
+}
+
+mod private {
+    /// Supertrait of the core marker traits, which prevents implementations outside of
+    /// this crate, since the arithmetic relies on `Zero`, `Succ` and `Pred` being the only
+    /// number types.
+    pub trait Sealed {}
+
+    /// Supertrait of `Sequence`, separate from `Sealed`, since it is implemented for
+    /// collections.
+    pub trait SequenceSealed {}
+}
 
 /// Basic trait implemented by all number types.
-pub trait NumType: Into<i64> + Into<i32> + Into<i16> + Into<i8> + Into<isize> {
+///
+/// This trait is sealed, so it can be used in bounds, but not implemented outside of
+/// this crate:
+///
+/// ```compile_fail
+/// struct Fake;
+///
+/// // all supertraits except the private `Sealed`
+/// macro_rules! from_fake {
+///     ($($t:ty = $v:expr),+) => { $(impl From<Fake> for $t { fn from(_: Fake) -> $t { $v } })+ };
+/// }
+/// from_fake!(i128 = 0, i64 = 0, i32 = 0, isize = 0, f64 = 0.0, f32 = 0.0);
+///
+/// impl tylar::NumType for Fake {
+///     const VALUE: i64 = 0;
+///     fn new() -> Self { Fake }
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a type-level number",
+    label = "expected a number type",
+    note = "number types are `Zero`, `Succ<N>` and `Pred<N>`, where `N` is a number type"
+)]
+pub trait NumType: private::Sealed + Into<i128> + Into<i64> + Into<i32> + Into<isize> + Into<f64> + Into<f32> {
+    /// The value of this number type, which can be used in constant expressions.
+    const VALUE: i64;
+    
     /// Creates a new instance of this number type, which is actually a no-op, since
     /// number types are zero-sized. Instances are useful, however, to be converted
     /// into actual integer values, using implementations of the `Into` trait.
-    #[inline(always)] fn new() -> Self;
+    /// Each number type also has an inherent `const fn new()`, which is used for
+    /// concrete number types.
+    fn new() -> Self;
+    
+    /// Returns the magnitude of this number as an unsigned integer. Unlike `Into<u64>`,
+    /// this is available for negative numbers as well.
+    #[inline(always)] fn abs_value(&self) -> u64 {
+        Self::VALUE.unsigned_abs()
+    }
+    
+    /// Returns the value of this number type as `f64`, without having to name `Into<f64>`.
+    #[inline(always)] fn to_f64() -> f64 {
+        Self::new().into()
+    }
+
+    /// Returns an instance of the magnitude of this number, i.e. of `<Self as Abs>::Out`.
+    #[inline(always)] fn magnitude(self) -> <Self as Abs>::Out where Self: Abs {
+        <Self as Abs>::Out::new()
+    }
+
+    /// Returns an instance of the successor of this number, i.e. of `<Self as Incr>::Out`.
+    #[inline(always)] fn incr(self) -> <Self as Incr>::Out where Self: Incr {
+        <Self as Incr>::Out::new()
+    }
+
+    /// Returns an instance of the predecessor of this number, i.e. of `<Self as Decr>::Out`.
+    #[inline(always)] fn decr(self) -> <Self as Decr>::Out where Self: Decr {
+        <Self as Decr>::Out::new()
+    }
+
+    /// Returns an instance of the negation of this number, i.e. of `<Self as Neg>::Out`.
+    #[inline(always)] fn negate(self) -> <Self as Neg>::Out where Self: Neg {
+        <Self as Neg>::Out::new()
+    }
+
+    /// Returns the value as `i8`, clamped to `i8::MIN..=i8::MAX`. Unlike `Into<i8>`, this
+    /// is available for all numbers.
+    #[inline(always)] fn saturating_into_i8(&self) -> i8 {
+        Self::VALUE.clamp(i8::MIN as i64, i8::MAX as i64) as i8
+    }
+
+    /// Returns the value as `i16`, clamped to `i16::MIN..=i16::MAX`.
+    #[inline(always)] fn saturating_into_i16(&self) -> i16 {
+        Self::VALUE.clamp(i16::MIN as i64, i16::MAX as i64) as i16
+    }
+
+    /// Returns the value as `u8`, clamped to `0..=u8::MAX`. Unlike `Into<u8>`, this is
+    /// available for all numbers, including negative ones.
+    #[inline(always)] fn saturating_into_u8(&self) -> u8 {
+        Self::VALUE.clamp(0, u8::MAX as i64) as u8
+    }
+
+    /// Returns the value as `u16`, clamped to `0..=u16::MAX`.
+    #[inline(always)] fn saturating_into_u16(&self) -> u16 {
+        Self::VALUE.clamp(0, u16::MAX as i64) as u16
+    }
+
+    /// Returns the value as `u32`, clamped to `0..=u32::MAX`.
+    #[inline(always)] fn saturating_into_u32(&self) -> u32 {
+        Self::VALUE.clamp(0, u32::MAX as i64) as u32
+    }
+
+    /// Returns the value as `i8`, wrapping around like `as i8` (in two's complement), e.g.
+    /// -126 for 130. Unlike `Into<i8>`, this is available for all numbers.
+    #[inline(always)] fn wrapping_into_i8(&self) -> i8 {
+        Self::VALUE as i8
+    }
+
+    /// Returns the value as `i16`, wrapping around like `as i16`.
+    #[inline(always)] fn wrapping_into_i16(&self) -> i16 {
+        Self::VALUE as i16
+    }
+
+    /// Returns the value as `u8`, wrapping around like `as u8`, e.g. 255 for -1.
+    #[inline(always)] fn wrapping_into_u8(&self) -> u8 {
+        Self::VALUE as u8
+    }
+
+    /// Returns the value as `u16`, wrapping around like `as u16`.
+    #[inline(always)] fn wrapping_into_u16(&self) -> u16 {
+        Self::VALUE as u16
+    }
+
+    /// Returns the value as `u32`, wrapping around like `as u32`.
+    #[inline(always)] fn wrapping_into_u32(&self) -> u32 {
+        Self::VALUE as u32
+    }
+
+    /// Checks whether a runtime value equals the value of this number type.
+    #[inline(always)] fn matches(v: i64) -> bool {
+        v == Self::VALUE
+    }
+
+    /// Returns an instance of this number type if the runtime value equals its value,
+    /// e.g. to check a length received at runtime before entering typed code:
+    ///
+    /// ```
+    /// use tylar::{NumType, P3};
+    ///
+    /// assert_eq!(P3::check(3), Some(P3::new()));
+    /// assert_eq!(P3::check(-3), None);
+    /// ```
+    #[inline(always)] fn check(v: i64) -> Option<Self> {
+        if Self::matches(v) { Some(Self::new()) } else { None }
+    }
 }
 
-/// Marker trait for positive numbers (including zero).
-pub trait PosType: NumType + Into<u64> + Into<u32> + Into<u16> + Into<u8> + Into<usize> {}
+/// Marker trait for positive numbers (including zero). This trait is sealed.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a positive type-level number (or zero)",
+    label = "expected a positive number (or zero)",
+    note = "this happens e.g. when a subtraction produced a negative number where a positive one was required"
+)]
+pub trait PosType: NumType + Into<u128> + Into<u64> + Into<u32> + Into<usize> {
+    /// The value of this number type as an unsigned integer.
+    const UVALUE: u64 = Self::VALUE as u64;
+    /// The value of this number type as `usize`, e.g. to be used as an array length:
+    ///
+    /// ```
+    /// use tylar::{PosType, P4};
+    ///
+    /// let buf = [0u8; P4::USIZE];
+    /// assert_eq!(buf.len(), 4);
+    /// ```
+    ///
+    /// This only works for concrete number types, because array lengths can't depend on
+    /// generic parameters (other than const generics) in stable Rust. In generic code, use
+    /// `make_array` instead, which ties a const generic length to the number type.
+    const USIZE: usize = Self::VALUE as usize;
 
-/// Marker trait for negative numbers (including zero).
+    /// Checks whether a runtime length equals the value of this number type.
+    #[inline(always)] fn matches_usize(v: usize) -> bool {
+        v as u64 == Self::UVALUE
+    }
+
+    /// Returns an instance of this number type if the runtime length equals its value.
+    #[inline(always)] fn check_usize(v: usize) -> Option<Self> {
+        if Self::matches_usize(v) { Some(Self::new()) } else { None }
+    }
+
+    /// Returns an instance of this number type if the runtime value equals its value.
+    #[inline(always)] fn check_u64(v: u64) -> Option<Self> {
+        if v == Self::UVALUE { Some(Self::new()) } else { None }
+    }
+}
+
+/// Marker trait for negative numbers (including zero). This trait is sealed.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a negative type-level number (or zero)",
+    label = "expected a negative number (or zero)",
+    note = "this happens e.g. when an addition produced a positive number where a negative one was required"
+)]
 pub trait NegType: NumType {}
 
+/// Marker trait for numbers other than zero. This trait is sealed.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a non-zero type-level number",
+    label = "expected a number other than zero"
+)]
+pub trait NonZero: NumType {}
+
 /// The number type for zero (0).
 #[allow(dead_code)]
 #[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
@@ -35,7 +355,82 @@ pub struct Pred<N> {
     phantom: PhantomData<N>
 }
 
+impl private::Sealed for Zero {}
+impl<N> private::Sealed for Succ<N> {}
+impl<N> private::Sealed for Pred<N> {}
+
+impl Zero {
+    /// Creates a new instance of this number type (see `NumType::new()`).
+    #[inline(always)] pub const fn new() -> Self { Zero }
+}
+
+impl<N> Succ<N> {
+    /// Creates a new instance of this number type (see `NumType::new()`).
+    #[inline(always)] pub const fn new() -> Self { Succ { phantom: PhantomData } }
+}
+
+impl<N> Pred<N> {
+    /// Creates a new instance of this number type (see `NumType::new()`).
+    #[inline(always)] pub const fn new() -> Self { Pred { phantom: PhantomData } }
+}
+
+// `Debug`, `Hash` and `Default` are implemented manually, because deriving them would
+// require `N` to implement them too, and `Debug` would print the whole `PhantomData` chain.
+macro_rules! impl_std_traits {
+    ($([$($param:ident),*] $ty:ty),*) => {$(
+        impl<$($param),*> Default for $ty {
+            #[inline(always)] fn default() -> Self { Self::new() }
+        }
+
+        impl<$($param),*> Hash for $ty {
+            #[inline(always)] fn hash<H: Hasher>(&self, _: &mut H) {}
+        }
+
+        /// Prints the value of the number, e.g. `-3` for `N3`.
+        impl<$($param),*> fmt::Display for $ty where $ty: NumType {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&<$ty as NumType>::VALUE, f)
+            }
+        }
+
+        /// Prints the name of the shorthand for the number, e.g. `N3` for `Pred<Pred<Pred<Zero>>>`.
+        impl<$($param),*> fmt::Debug for $ty where $ty: NumType {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match <$ty as NumType>::VALUE {
+                    0 => f.write_str("Zero"),
+                    v if v > 0 => write!(f, "P{}", v),
+                    v => write!(f, "N{}", v.unsigned_abs()),
+                }
+            }
+        }
+    )*}
+}
+
+impl_std_traits!([] Zero, [N] Succ<N>, [N] Pred<N>);
+
+// comparisons with primitive integers, in terms of the value (only with the number on the left,
+// because impls like `PartialEq<Succ<N>> for i32` would break inference of `assert_eq!(5, x.into())`)
+macro_rules! impl_cmp_primitive {
+    ($($ity:ty)+; $types:tt) => {$(
+        impl_cmp_primitive!(@each $ity; $types);
+    )+};
+    (@each $ity:ty; [$([$($param:ident),*] $ty:ty),*]) => {$(
+        impl<$($param),*> PartialEq<$ity> for $ty where $ty: NumType {
+            #[inline(always)] fn eq(&self, other: &$ity) -> bool { <$ty as NumType>::VALUE as i128 == *other as i128 }
+        }
+
+        impl<$($param),*> PartialOrd<$ity> for $ty where $ty: NumType {
+            #[inline(always)] fn partial_cmp(&self, other: &$ity) -> Option<Ordering> {
+                (<$ty as NumType>::VALUE as i128).partial_cmp(&(*other as i128))
+            }
+        }
+    )*};
+}
+
+impl_cmp_primitive!(i64 i32 i16 i8 isize u64 u32 u16 u8 usize; [[] Zero, [N] Succ<N>, [N] Pred<N>]);
+
 impl NumType for Zero {
+    const VALUE: i64 = 0;
     #[inline(always)] fn new() -> Self { Zero }
 }
 
@@ -43,46 +438,126 @@ impl PosType for Zero {}
 impl NegType for Zero {}
 
 impl<N: NumType> NumType for Succ<N> {
-    #[inline(always)] fn new() -> Self { Succ { phantom: PhantomData } }
+    const VALUE: i64 = N::VALUE + 1;
+    #[inline(always)] fn new() -> Self { Succ::new() }
 }
 
 impl<N: NumType> NumType for Pred<N> {
-    #[inline(always)] fn new() -> Self { Pred { phantom: PhantomData } }
+    const VALUE: i64 = N::VALUE - 1;
+    #[inline(always)] fn new() -> Self { Pred::new() }
 }
 
 impl<N: PosType> PosType for Succ<N> {}
 impl<N: NegType> NegType for Pred<N> {}
+impl<N: PosType> NonZero for Succ<N> {}
+impl<N: NegType> NonZero for Pred<N> {}
 
-macro_rules! impl_into_signed {
-    ($($ity:ty)+) => ($(
-        impl<N: NumType> Into<$ity> for Succ<N> {
-            #[inline(always)] fn into(self) -> $ity { Into::<$ity>::into(N::new()) + 1 }
+// all conversions cast the value, which is computed as `i64` at compile time, only once,
+// instead of adding up one step per `Succ` or `Pred` in the target type
+macro_rules! impl_from_signed {
+    ($zero:literal; $($ity:ty)+) => ($(
+        impl<N: NumType> From<Succ<N>> for $ity {
+            #[inline(always)] fn from(_: Succ<N>) -> $ity { <Succ<N> as NumType>::VALUE as $ity }
         }
 
-        impl<N: NumType> Into<$ity> for Pred<N> {
-            #[inline(always)] fn into(self) -> $ity { Into::<$ity>::into(N::new()) - 1 }
+        impl<N: NumType> From<Pred<N>> for $ity {
+            #[inline(always)] fn from(_: Pred<N>) -> $ity { <Pred<N> as NumType>::VALUE as $ity }
         }
 
-        impl Into<$ity> for Zero {
-            #[inline(always)] fn into(self) -> $ity { 0 }
+        impl From<Zero> for $ity {
+            #[inline(always)] fn from(_: Zero) -> $ity { $zero }
         }
     )+)
 }
 
-macro_rules! impl_into_unsigned {
+macro_rules! impl_from_unsigned {
     ($($ity:ty)+) => ($(
-        impl<N: PosType> Into<$ity> for Succ<N> {
-            #[inline(always)] fn into(self) -> $ity { Into::<$ity>::into(N::new()) + 1 }
+        impl<N: PosType> From<Succ<N>> for $ity {
+            #[inline(always)] fn from(_: Succ<N>) -> $ity { <Succ<N> as PosType>::UVALUE as $ity }
         }
 
-        impl Into<$ity> for Zero {
-            #[inline(always)] fn into(self) -> $ity { 0 }
+        impl From<Zero> for $ity {
+            #[inline(always)] fn from(_: Zero) -> $ity { 0 }
         }
     )+)
 }
 
-impl_into_signed!(i64 i32 i16 i8 isize);
-impl_into_unsigned!(u64 u32 u16 u8 usize);
+// narrow integer types are only converted into if the value fits (see `FitsIn`), so the
+// cast never truncates
+macro_rules! impl_from_narrow {
+    ($($ity:ty)+) => ($(
+        impl<N: NumType> From<Succ<N>> for $ity where Succ<N>: FitsIn<$ity> {
+            #[inline(always)] fn from(_: Succ<N>) -> $ity { <Succ<N> as NumType>::VALUE as $ity }
+        }
+
+        impl<N: NumType> From<Pred<N>> for $ity where Pred<N>: FitsIn<$ity> {
+            #[inline(always)] fn from(_: Pred<N>) -> $ity { <Pred<N> as NumType>::VALUE as $ity }
+        }
+
+        impl From<Zero> for $ity {
+            #[inline(always)] fn from(_: Zero) -> $ity { 0 }
+        }
+    )+)
+}
+
+impl_from_signed!(0; i128 i64 i32 isize);
+impl_from_signed!(0.0; f64 f32);
+impl_from_unsigned!(u128 u64 u32 usize);
+impl_from_narrow!(i16 i8 u16 u8);
+
+// the type-level proof that `Succ<N>` and `Pred<N>` are non-zero is the `PosType`/`NegType`
+// bound on `N`, which makes `new_unchecked` sound
+impl<N: PosType> From<Succ<N>> for NonZeroU64 {
+    #[inline(always)] fn from(_: Succ<N>) -> NonZeroU64 { unsafe { NonZeroU64::new_unchecked(N::UVALUE + 1) } }
+}
+
+impl<N: PosType> From<Succ<N>> for NonZeroI64 {
+    #[inline(always)] fn from(_: Succ<N>) -> NonZeroI64 { unsafe { NonZeroI64::new_unchecked(N::VALUE + 1) } }
+}
+
+impl<N: NegType> From<Pred<N>> for NonZeroI64 {
+    #[inline(always)] fn from(_: Pred<N>) -> NonZeroI64 { unsafe { NonZeroI64::new_unchecked(N::VALUE - 1) } }
+}
+
+/// Marker trait for numbers whose value is in the range of the primitive integer type `T`.
+///
+/// This is implemented for `T` = `i8`, `u8`, `i16` and `u16`, and the conversions into these
+/// types require it, so that a number which is out of range fails to compile instead of
+/// overflowing at runtime:
+///
+/// ```compile_fail
+/// #![recursion_limit = "256"]
+/// use tylar::{NumType, Plus100, Plus10, P3};
+///
+/// let x: i8 = <Plus100<Plus10<Plus10<Plus10<P3>>>>>::new().into(); // 133
+/// ```
+///
+/// Conversions into wider types are always available. All conversions cast the value
+/// once, so they never overflow on the way up to the number. The range of the 16-bit types only
+/// restricts the sign, since their bounds are out of reach for number types in practice.
+#[diagnostic::on_unimplemented(
+    message = "the value of `{Self}` is out of range for `{T}`",
+    label = "doesn't fit into `{T}`"
+)]
+pub trait FitsIn<T>: NumType {}
+impl<N: NumType> FitsIn<i8> for N where N: Within<Plus100<Plus10<Plus10<Plus5<Succ<Succ<Zero>>>>>>, Plus100<Plus10<Plus10<Plus5<Succ<Succ<Succ<Zero>>>>>>>> {}
+impl<N: NumType> FitsIn<u8> for N where N: Within<Plus100<Plus100<Plus50<Plus5<Zero>>>>, Zero> {}
+// the bounds of the 16-bit types are far beyond the numbers that rustc can handle at all
+// (it overflows its stack at a few thousand), so these only need to check the sign
+impl<N: NumType> FitsIn<i16> for N {}
+impl<N: PosType> FitsIn<u16> for N {}
+
+/// Checks that a number is within the range given by the magnitudes of its maximum (`Max`)
+/// and minimum (`Min`), by counting both down, used to implement `FitsIn`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "number type is out of range for the target type",
+    note = "see `FitsIn` for the supported range of narrow integer types"
+)]
+pub trait Within<Max, Min> {}
+impl<Max, Min> Within<Max, Min> for Zero {}
+impl<N, Max, Min> Within<Succ<Max>, Min> for Succ<N> where N: Within<Max, Min> {}
+impl<N, Max, Min> Within<Max, Succ<Min>> for Pred<N> where N: Within<Max, Min> {}
 
 /// Negation of number types.
 pub trait Neg: NumType {
@@ -93,6 +568,110 @@ impl Neg for Zero { type Out = Zero; }
 impl<A: PosType, B: NegType> Neg for Succ<A> where A: Neg<Out=B> { type Out = Pred<B>; }
 impl<A: NegType, B: PosType> Neg for Pred<A> where A: Neg<Out=B> { type Out = Succ<B>; }
 
+/// Marker trait for the signs of number types, i.e. `Positive`, `Negative` and `Neutral`.
+/// This trait is sealed.
+pub trait SignType: private::Sealed {}
+
+/// The sign of strictly positive numbers.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct Positive;
+
+/// The sign of strictly negative numbers.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct Negative;
+
+/// The sign of zero.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct Neutral;
+
+impl private::Sealed for Positive {}
+impl private::Sealed for Negative {}
+impl private::Sealed for Neutral {}
+
+impl SignType for Positive {}
+impl SignType for Negative {}
+impl SignType for Neutral {}
+
+/// Sign of number types.
+///
+/// `Zero` implements both `PosType` and `NegType`, so blanket impls keyed on these two
+/// markers always overlap. The sign on the other hand is unique, so code that needs
+/// different implementations for positive and negative numbers should dispatch on it
+/// with a helper trait instead:
+///
+/// ```
+/// use tylar::{NumType, PosType, NegType, Sign, Positive, Negative, Neutral, P3, N3};
+///
+/// trait Describe { fn describe() -> &'static str; }
+///
+/// trait DescribeBySign<N> { fn describe() -> &'static str; }
+/// impl<N: PosType> DescribeBySign<N> for Positive { fn describe() -> &'static str { "positive" } }
+/// impl<N: NegType> DescribeBySign<N> for Negative { fn describe() -> &'static str { "negative" } }
+/// impl<N: NumType> DescribeBySign<N> for Neutral { fn describe() -> &'static str { "zero" } }
+///
+/// impl<N: Sign> Describe for N where N::Out: DescribeBySign<N> {
+///     fn describe() -> &'static str { <N::Out as DescribeBySign<N>>::describe() }
+/// }
+///
+/// assert_eq!("positive", P3::describe());
+/// assert_eq!("negative", N3::describe());
+/// ```
+pub trait Sign: NumType {
+    /// Result of the operation, i.e. `Positive`, `Negative` or `Neutral`.
+    type Out: SignType;
+}
+impl Sign for Zero { type Out = Neutral; }
+impl<A: PosType> Sign for Succ<A> { type Out = Positive; }
+impl<A: NegType> Sign for Pred<A> { type Out = Negative; }
+
+/// Normalization of number types.
+///
+/// Number types are canonical if they are either `Zero`, or consist only of `Succ` or only
+/// of `Pred` wrappers around `Zero`. Non-canonical nestings such as `Succ<Pred<Zero>>` are
+/// valid number types (representing 0 in this example), but they are not the same type as
+/// their canonical form, so they are rejected by the arithmetic operations. `Normalize`
+/// collapses them to their canonical form, which can then be used as usual.
+///
+/// ```compile_fail
+/// use tylar::{NumType, Add, Zero, Succ, Pred, P1};
+///
+/// let x: i32 = <Succ<Pred<Zero>> as Add<P1>>::Out::new().into();
+/// ```
+pub trait Normalize: NumType {
+    /// Result of the operation, i.e. the canonical form of `Self`.
+    type Out: NumType;
+}
+impl Normalize for Zero { type Out = Zero; }
+impl<N, M: Incr> Normalize for Succ<N> where N: Normalize<Out=M> { type Out = M::Out; }
+impl<N, M: Decr> Normalize for Pred<N> where N: Normalize<Out=M> { type Out = M::Out; }
+
+/// Returns an instance of the canonical form of `T` (see `Normalize`).
+#[inline(always)]
+pub fn normalize<T: Normalize>() -> T::Out {
+    T::Out::new()
+}
+
+/// Nesting depth of number types, i.e. the total number of `Succ` and `Pred` wrappers.
+/// For canonical numbers this is the magnitude of the value, whereas non-canonical
+/// nestings are deeper (e.g. `Succ<Pred<Zero>>` has depth 2, but value 0), which makes
+/// them slower to typecheck.
+pub trait Depth: NumType {
+    /// The nesting depth of `Self`.
+    const DEPTH: usize;
+}
+impl Depth for Zero { const DEPTH: usize = 0; }
+impl<N: Depth> Depth for Succ<N> { const DEPTH: usize = N::DEPTH + 1; }
+impl<N: Depth> Depth for Pred<N> { const DEPTH: usize = N::DEPTH + 1; }
+
+/// Absolute value of number types.
+pub trait Abs: NumType {
+    /// Result of the operation, i.e. `Out` = |`Self`|.
+    type Out: PosType;
+}
+impl Abs for Zero { type Out = Zero; }
+impl<A: PosType> Abs for Succ<A> { type Out = Succ<A>; }
+impl<A: NegType, B: PosType> Abs for Pred<A> where Pred<A>: Neg<Out=B> { type Out = B; }
+
 /// Incrementation of number types.
 pub trait Incr: NumType {
     /// Result of the operation, i.e. `Out` = `Self` + 1.
@@ -112,25 +691,159 @@ impl<A: PosType> Decr for Succ<A> { type Out = A; }
 impl<A: NegType> Decr for Pred<A> { type Out = Pred<Pred<A>>; }
 
 /// Addition of number types.
+#[diagnostic::on_unimplemented(
+    message = "cannot add `{RHS}` to `{Self}` at the type level",
+    note = "both operands must be canonical number types (see `Normalize`)"
+)]
 pub trait Add<RHS>: NumType {
     /// Result of the operation, i.e. `Out` = `Self` + `RHS`.
     type Out: NumType;
 }
-impl<RHS: NumType> Add<RHS> for Zero { type Out = RHS; }
+// `Incr` is only implemented for canonical numbers, so this rejects non-canonical `RHS`
+impl<RHS: NumType> Add<RHS> for Zero where RHS: Incr { type Out = RHS; }
 impl<A: PosType, RHS, B: NumType> Add<RHS> for Succ<A> where RHS: Incr<Out=B>, A: Add<B>  { type Out = A::Out; }
 impl<A: NegType, RHS, B: NumType> Add<RHS> for Pred<A> where RHS: Decr<Out=B>, A: Add<B>  { type Out = A::Out; }
 
 /// Subtraction of number types.
+#[diagnostic::on_unimplemented(
+    message = "cannot subtract `{RHS}` from `{Self}` at the type level",
+    note = "both operands must be canonical number types (see `Normalize`)"
+)]
 pub trait Sub<RHS>: NumType {
     /// Result of the operation, i.e. `Out` = `Self` – `RHS`.
     type Out: NumType;
 }
 impl<A, RHS, B: NumType> Sub<RHS> for A where RHS: Neg<Out=B>, A: Add<B> { type Out = A::Out; }
 
+/// Addition of a constant to number types, e.g. `<P3 as AddConst<4>>::Out` is `P7`.
+/// This is implemented by repeatedly applying `Incr` or `Decr`, for constants in the
+/// range `-32..=32`.
+pub trait AddConst<const N: i64>: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` + `N`.
+    type Out: NumType;
+}
+impl<A: NumType> AddConst<0> for A { type Out = A; }
+
+macro_rules! impl_add_const {
+    ($op:ident: $($n:literal => $prev:literal),+) => ($(
+        impl<A, B> AddConst<{$n}> for A where A: $op<Out=B>, B: AddConst<{$prev}> {
+            type Out = <B as AddConst<{$prev}>>::Out;
+        }
+    )+)
+}
+
+impl_add_const!(Incr:
+    1 => 0, 2 => 1, 3 => 2, 4 => 3, 5 => 4, 6 => 5, 7 => 6, 8 => 7, 9 => 8, 10 => 9, 11 => 10,
+    12 => 11, 13 => 12, 14 => 13, 15 => 14, 16 => 15, 17 => 16, 18 => 17, 19 => 18, 20 => 19,
+    21 => 20, 22 => 21, 23 => 22, 24 => 23, 25 => 24, 26 => 25, 27 => 26, 28 => 27, 29 => 28,
+    30 => 29, 31 => 30, 32 => 31);
+impl_add_const!(Decr:
+    -1 => 0, -2 => -1, -3 => -2, -4 => -3, -5 => -4, -6 => -5, -7 => -6, -8 => -7, -9 => -8,
+    -10 => -9, -11 => -10, -12 => -11, -13 => -12, -14 => -13, -15 => -14, -16 => -15,
+    -17 => -16, -18 => -17, -19 => -18, -20 => -19, -21 => -20, -22 => -21, -23 => -22,
+    -24 => -23, -25 => -24, -26 => -25, -27 => -26, -28 => -27, -29 => -28, -30 => -29,
+    -31 => -30, -32 => -31);
+
+/// Subtraction of a constant from number types, e.g. `<P7 as SubConst<4>>::Out` is `P3`.
+/// This is implemented in terms of `AddConst`, for constants in the range `-32..=32`.
+pub trait SubConst<const N: i64>: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` – `N`.
+    type Out: NumType;
+}
+
+macro_rules! impl_sub_const {
+    ($($n:literal => $neg:literal),+) => ($(
+        impl<A, B: NumType> SubConst<{$n}> for A where A: AddConst<{$neg}, Out=B> { type Out = B; }
+    )+)
+}
+
+impl_sub_const!(
+    -32 => 32, -31 => 31, -30 => 30, -29 => 29, -28 => 28, -27 => 27, -26 => 26, -25 => 25,
+    -24 => 24, -23 => 23, -22 => 22, -21 => 21, -20 => 20, -19 => 19, -18 => 18, -17 => 17,
+    -16 => 16, -15 => 15, -14 => 14, -13 => 13, -12 => 12, -11 => 11, -10 => 10, -9 => 9,
+    -8 => 8, -7 => 7, -6 => 6, -5 => 5, -4 => 4, -3 => 3, -2 => 2, -1 => 1, 0 => 0, 1 => -1,
+    2 => -2, 3 => -3, 4 => -4, 5 => -5, 6 => -6, 7 => -7, 8 => -8, 9 => -9, 10 => -10,
+    11 => -11, 12 => -12, 13 => -13, 14 => -14, 15 => -15, 16 => -16, 17 => -17, 18 => -18,
+    19 => -19, 20 => -20, 21 => -21, 22 => -22, 23 => -23, 24 => -24, 25 => -25, 26 => -26,
+    27 => -27, 28 => -28, 29 => -29, 30 => -30, 31 => -31, 32 => -32);
+
+/// Marker trait for numbers whose value is at least the constant `N`, so that bounds like
+/// `T: AtLeast<4>` don't need to name the number type for the constant. This is implemented
+/// in terms of `SubConst`, so `N` must be in the range `-32..=32`.
+///
+/// ```
+/// use tylar::{AtLeast, NumType, P4, P9};
+///
+/// fn needs_four<T: AtLeast<4>>() -> i64 { T::VALUE }
+///
+/// assert_eq!(needs_four::<P4>(), 4);
+/// assert_eq!(needs_four::<P9>(), 9);
+/// ```
+///
+/// Smaller numbers are rejected:
+///
+/// ```compile_fail
+/// use tylar::{AtLeast, NumType, P3};
+///
+/// fn needs_four<T: AtLeast<4>>() -> i64 { T::VALUE }
+///
+/// needs_four::<P3>();
+/// ```
+pub trait AtLeast<const N: i64>: NumType {}
+impl<const N: i64, A, D: IsNotNegative> AtLeast<N> for A where A: SubConst<N, Out=D> {}
+
+/// Marker trait for numbers whose value is at most the constant `N`, the counterpart of
+/// `AtLeast`. `N` must be in the range `-32..=32`.
+///
+/// ```
+/// use tylar::{AtMost, NumType, N2, Zero};
+///
+/// fn non_positive<T: AtMost<0>>() -> i64 { T::VALUE }
+///
+/// assert_eq!(non_positive::<Zero>(), 0);
+/// assert_eq!(non_positive::<N2>(), -2);
+/// ```
+///
+/// Larger numbers are rejected:
+///
+/// ```compile_fail
+/// use tylar::{AtMost, NumType, P1};
+///
+/// fn non_positive<T: AtMost<0>>() -> i64 { T::VALUE }
+///
+/// non_positive::<P1>();
+/// ```
+pub trait AtMost<const N: i64>: NumType {}
+impl<const N: i64, A, D: IsNotPositive> AtMost<N> for A where A: SubConst<N, Out=D> {}
+
+/// Checks that the difference to the bound is not negative, used to implement `AtLeast`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "number is less than the required minimum, the difference is `{Self}`",
+    label = "value too small"
+)]
+pub trait IsNotNegative {}
+impl IsNotNegative for Zero {}
+impl<N: PosType> IsNotNegative for Succ<N> {}
+
+/// Checks that the difference to the bound is not positive, used to implement `AtMost`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "number is greater than the allowed maximum, the difference is `{Self}`",
+    label = "value too large"
+)]
+pub trait IsNotPositive {}
+impl IsNotPositive for Zero {}
+impl<N: NegType> IsNotPositive for Pred<N> {}
+
 /// Halving of number types.
 /// `Div<_,P2>` could be used instead of this, but `Div` stresses the typechecker more
 /// than `Halve`, so that `Halve` can be used with larger numbers without running into
 /// the recursion limit.
+#[diagnostic::on_unimplemented(
+    message = "cannot halve `{Self}` at the type level",
+    note = "`Halve` is only implemented for even numbers"
+)]
 pub trait Halve: NumType {
     /// Result of the operation, i.e. `Out` = `Self` / 2.
     type Out: NumType;
@@ -139,114 +852,2842 @@ impl Halve for Zero { type Out = Zero; }
 impl<A: PosType, B: NumType> Halve for Succ<Succ<A>> where A: Halve<Out=B>  { type Out = Succ<B>; }
 impl<A: NegType, B: NumType> Halve for Pred<Pred<A>> where A: Halve<Out=B>  { type Out = Pred<B>; }
 
-/// Subtraction of number types.
+/// Halving of even number types, which spells out that odd numbers are rejected at compile
+/// time (rather than rounded, as by `HalveFloor`). This is the same as `Halve`.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{HalveExact, N3, N6};
+///
+/// # fn main() {
+/// type_eq!(<N6 as HalveExact>::Out, N3);
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// use tylar::{HalveExact, NumType, P3};
+///
+/// let _ = <P3 as HalveExact>::Out::new();
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is odd and cannot be halved exactly",
+    note = "use `HalveFloor` to round towards negative infinity instead"
+)]
+pub trait HalveExact: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` / 2.
+    type Out: NumType;
+}
+impl<A, E> HalveExact for A where A: Parity<Out=E>, E: HalveIfEven<A> { type Out = E::Out; }
+
+/// Halves `A` by its parity, used to implement `HalveExact`. Not implemented for `False`,
+/// so that odd numbers are rejected by this trait directly.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{A}` is odd and cannot be halved exactly",
+    label = "expected an even number",
+    note = "use `HalveFloor` to round towards negative infinity instead"
+)]
+pub trait HalveIfEven<A>: BoolType {
+    type Out: NumType;
+}
+impl<A: Halve> HalveIfEven<A> for True { type Out = A::Out; }
+
+/// Doubling of number types, the inverse of `Halve`.
+/// Like `Halve`, this is cheaper for the typechecker than `Add<Self>` or `Mul<P2>`.
+pub trait Double: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` * 2.
+    type Out: NumType;
+}
+impl Double for Zero { type Out = Zero; }
+impl<A: PosType, B: PosType> Double for Succ<A> where A: Double<Out=B> { type Out = Succ<Succ<B>>; }
+impl<A: NegType, B: NegType> Double for Pred<A> where A: Double<Out=B> { type Out = Pred<Pred<B>>; }
+
+/// Tripling of number types, which, like `Double`, is cheaper for the typechecker than `Mul<P3>`.
+pub trait Triple: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` * 3.
+    type Out: NumType;
+}
+impl Triple for Zero { type Out = Zero; }
+impl<A: PosType, B: PosType> Triple for Succ<A> where A: Triple<Out=B> { type Out = Succ<Succ<Succ<B>>>; }
+impl<A: NegType, B: NegType> Triple for Pred<A> where A: Triple<Out=B> { type Out = Pred<Pred<Pred<B>>>; }
+
+/// Quadrupling of number types, implemented by doubling twice.
+pub trait Quadruple: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` * 4.
+    type Out: NumType;
+}
+impl<A, B, C: NumType> Quadruple for A where A: Double<Out=B>, B: Double<Out=C> { type Out = C; }
+
+/// Left shift of number types, i.e. multiplication by 2<sup>`E`</sup>, implemented by
+/// applying `Double` `E` times.
+pub trait Shl<E: PosType>: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` * 2<sup>`E`</sup>.
+    type Out: NumType;
+}
+impl<A: NumType> Shl<Zero> for A { type Out = A; }
+impl<A, E: PosType, D> Shl<Succ<E>> for A where A: Double<Out=D>, D: Shl<E> { type Out = D::Out; }
+
+/// Right shift of number types, i.e. division by 2<sup>`E`</sup>, rounding towards negative
+/// infinity like `>>` on signed integers in Rust (and unlike `Div`, which truncates).
+///
+/// For multiples of 2<sup>`E`</sup> this is the same as applying `Halve` `E` times. Otherwise,
+/// e.g. `<N5 as Shr<P1>>::Out` is `N3`, and shifting by more than the number of bits
+/// yields `Zero` for positive and `N1` for negative numbers.
+pub trait Shr<E: PosType>: NumType {
+    /// Result of the operation, i.e. `Out` = ⌊`Self` / 2<sup>`E`</sup>⌋.
+    type Out: NumType;
+}
+impl<A: NumType> Shr<Zero> for A { type Out = A; }
+impl<A, E: PosType, H> Shr<Succ<E>> for A where A: HalveFloor<Out=H>, H: Shr<E> { type Out = H::Out; }
+
+/// Halving of number types, rounding towards negative infinity (like `>> 1` for integers),
+/// so unlike `Halve`, this is implemented for odd numbers as well.
+pub trait HalveFloor: NumType {
+    /// Result of the operation, i.e. `Out` = ⌊`Self` / 2⌋.
+    type Out: NumType;
+}
+impl HalveFloor for Zero { type Out = Zero; }
+impl HalveFloor for Succ<Zero> { type Out = Zero; }
+impl HalveFloor for Pred<Zero> { type Out = Pred<Zero>; }
+impl<A: PosType, B: NumType> HalveFloor for Succ<Succ<A>> where A: HalveFloor<Out=B> { type Out = Succ<B>; }
+impl<A: NegType, B: NumType> HalveFloor for Pred<Pred<A>> where A: HalveFloor<Out=B> { type Out = Pred<B>; }
+
+/// Multiplication of number types.
+///
+/// The recursion runs over the operand with the smaller magnitude, so that the cost of
+/// the operation doesn't depend on the order of the arguments.
+#[diagnostic::on_unimplemented(
+    message = "cannot multiply `{Self}` by `{RHS}` at the type level",
+    note = "both operands must be canonical number types (see `Normalize`)"
+)]
 pub trait Mul<RHS>: NumType {
     /// Result of the operation, i.e. `Out` = `Self` * `RHS`.
     type Out: NumType;
 }
-impl<N: NumType> Mul<N> for Zero { type Out = Zero; }
-impl<A: PosType, RHS, B: NumType> Mul<RHS> for Succ<A> where A: Mul<RHS, Out=B>, RHS: Add<B> { type Out = RHS::Out; }
-impl<A: NegType, RHS, B, C: NumType> Mul<RHS> for Pred<A> where A: Mul<RHS, Out=C>, RHS: Neg<Out=B>, B: Add<C> { type Out = B::Out; }
+impl<A, RHS, AA: NumType, RR: NumType, D> Mul<RHS> for A
+    where A: Abs<Out=AA>, RHS: Abs<Out=RR>, AA: Sub<RR, Out=D>, D: MulSwap<A, RHS> { type Out = D::Out; }
+
+/// Multiplication by recursion over `Self`, used to implement `Mul`.
+#[doc(hidden)]
+pub trait MulRec<RHS>: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` * `RHS`.
+    type Out: NumType;
+}
+impl<N: NumType> MulRec<N> for Zero { type Out = Zero; }
+impl<A: PosType, RHS, B: NumType> MulRec<RHS> for Succ<A> where A: MulRec<RHS, Out=B>, RHS: Add<B> { type Out = RHS::Out; }
+impl<A: NegType, RHS, B, C: NumType> MulRec<RHS> for Pred<A> where A: MulRec<RHS, Out=C>, RHS: Neg<Out=B>, B: Add<C> { type Out = B::Out; }
+
+/// Selects the operand to recurse over, by the sign of `Self` = |`A`| – |`B`|.
+#[doc(hidden)]
+pub trait MulSwap<A, B> {
+    type Out: NumType;
+}
+impl<N, A, B> MulSwap<A, B> for Pred<N> where A: MulRec<B> { type Out = A::Out; }
+impl<A, B> MulSwap<A, B> for Zero where B: MulRec<A> { type Out = B::Out; }
+impl<N: PosType, A, B> MulSwap<A, B> for Succ<N> where B: MulRec<A> { type Out = B::Out; }
+
+/// The number of cells of a grid with `Self` rows and `C` columns, i.e. `Mul` for positive
+/// dimensions, with a positive result, e.g. `P6` for `P2` and `P3`. This is useful to size
+/// the storage of a type with dimensions:
+///
+/// ```
+/// use tylar::{Area, PosType, P2, P3};
+/// use std::marker::PhantomData;
+///
+/// struct Matrix<R: PosType + Area<C>, C: PosType> {
+///     cells: Vec<f64>,
+///     dims: PhantomData<(R, C)>,
+/// }
+///
+/// impl<R: PosType + Area<C>, C: PosType> Matrix<R, C> {
+///     fn new() -> Self {
+///         Matrix { cells: Vec::with_capacity(<R as Area<C>>::Out::USIZE), dims: PhantomData }
+///     }
+/// }
+///
+/// let m = Matrix::<P2, P3>::new();
+/// assert!(m.cells.capacity() >= 6);
+/// ```
+pub trait Area<C: PosType>: PosType {
+    /// Result of the operation, i.e. `Out` = `Self` * `C`.
+    type Out: PosType;
+}
+impl<R: PosType, C: PosType, A: PosType> Area<C> for R where R: Mul<C, Out=A> { type Out = A; }
+
+/// The number of cells of a box with the dimensions `Self`, `B` and `C`, like `Area` for
+/// three dimensions, e.g. `P24` for `P2`, `P3` and `P4`.
+pub trait Volume<B: PosType, C: PosType>: PosType {
+    /// Result of the operation, i.e. `Out` = `Self` * `B` * `C`.
+    type Out: PosType;
+}
+impl<A: PosType, B: PosType, C: PosType, X: PosType, V: PosType> Volume<B, C> for A
+    where A: Area<B, Out=X>, X: Area<C, Out=V> { type Out = V; }
 
-/// Division of number types.
+/// Division of number types, rounding towards zero (like integer division in Rust).
+///
+/// This is implemented by binary long division: the divisor is doubled until it exceeds
+/// the dividend, and the quotient is then built up bit by bit on the way back. The
+/// nesting depth is therefore logarithmic in the quotient, instead of linear as with
+/// repeated subtraction.
+#[diagnostic::on_unimplemented(
+    message = "cannot divide `{Self}` by `{RHS}` at the type level",
+    note = "division by `Zero` is not defined, and both operands must be canonical number types"
+)]
 pub trait Div<RHS>: NumType {
     /// Result of the operation, i.e. `Out` = `Self` / `RHS`.
     type Out: NumType;
 }
 impl<A: PosType> Div<Succ<A>> for Zero { type Out = Zero; }
 impl<A: NegType> Div<Pred<A>> for Zero { type Out = Zero; }
-impl<A: NumType, B: NumType, C: NumType> Div<Succ<B>> for Succ<A> where A: Sub<B, Out=C>, C: Div<Succ<B>> { type Out = Succ<C::Out>; }
-impl<N: NegType, NN: NegType, P: PosType, PP: PosType> Div<Pred<NN>> for Pred<N>
-    where N: Neg<Out=P>, NN: Neg<Out=PP>, Succ<P>: Div<Succ<PP>> { type Out = <Succ<P> as Div<Succ<PP>>>::Out; }
-impl<P: NumType, N: NegType, PP: NumType, PPP: NumType> Div<Pred<N>> for Succ<P>
-    where N: Neg<Out=PP>, Succ<P>: Div<Succ<PP>, Out=Succ<PPP>>, Succ<PPP>: Neg { type Out = <Succ<PPP> as Neg>::Out; }
-impl<P: NumType, N: NegType, PP: NumType, PPP: NumType> Div<Succ<P>> for Pred<N>
-    where N: Neg<Out=PP>, Succ<PP>: Div<Succ<P>, Out=Succ<PPP>>, Succ<PPP>: Neg { type Out = <Succ<PPP> as Neg>::Out; }
+impl<A: PosType, B: PosType, Q: NumType> Div<Succ<B>> for Succ<A> where Succ<A>: DivRem<Succ<B>, Quot=Q> { type Out = Q; }
+impl<N: NegType, NN: NegType, P: PosType, PP: PosType, Q: NumType> Div<Pred<NN>> for Pred<N>
+    where Pred<N>: Neg<Out=P>, Pred<NN>: Neg<Out=PP>, P: DivRem<PP, Quot=Q> { type Out = Q; }
+impl<P: PosType, N: NegType, PP: PosType, Q: NumType> Div<Pred<N>> for Succ<P>
+    where Pred<N>: Neg<Out=PP>, Succ<P>: DivRem<PP, Quot=Q>, Q: Neg { type Out = Q::Out; }
+impl<P: PosType, N: NegType, PP: PosType, Q: NumType> Div<Succ<P>> for Pred<N>
+    where Pred<N>: Neg<Out=PP>, PP: DivRem<Succ<P>, Quot=Q>, Q: Neg { type Out = Q::Out; }
 
-/// Shorthand for the number 1 (the first successor of zero).
-pub type P1 = Succ<Zero>;
-/// Shorthand for the number 2 (the second successor of zero).
-pub type P2 = Succ<P1>;
-/// Shorthand for the number 3 (the third successor of zero).
-pub type P3 = Succ<P2>;
-/// Shorthand for the number 4 (the fourth successor of zero).
-pub type P4 = Succ<P3>;
-/// Shorthand for the number 5 (the fifth successor of zero).
-pub type P5 = Succ<P4>;
-/// Shorthand for the number 6 (the sixth successor of zero).
-pub type P6 = Succ<P5>;
-/// Shorthand for the number 7 (the seventh successor of zero).
-pub type P7 = Succ<P6>;
-/// Shorthand for the number 8 (the eighth successor of zero).
-pub type P8 = Succ<P7>;
-/// Shorthand for the number 9 (the nineth successor of zero).
-pub type P9 = Succ<P8>;
+/// Umbrella trait for the four basic operations with the same right-hand side, so that a
+/// single bound `A: Arith<B>` gives access to all of their results. This is implemented
+/// whenever `Add`, `Sub`, `Mul` and `Div` are, so `Rhs` must not be `Zero`.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{Arith, NumType, P2, P4, P8, N4};
+///
+/// fn ops<A: Arith<B>, B: NumType>() -> (i64, i64, i64, i64) {
+///     (A::Sum::VALUE, A::Diff::VALUE, A::Prod::VALUE, A::Quot::VALUE)
+/// }
+///
+/// # fn main() {
+/// assert_eq!(ops::<P8, N4>(), (4, 12, -32, -2));
+/// type_eq!(<P4 as Arith<P2>>::Quot, P2);
+/// # }
+/// ```
+pub trait Arith<Rhs>: NumType {
+    /// The sum, i.e. `Self` + `Rhs`.
+    type Sum: NumType;
+    /// The difference, i.e. `Self` – `Rhs`.
+    type Diff: NumType;
+    /// The product, i.e. `Self` * `Rhs`.
+    type Prod: NumType;
+    /// The quotient, i.e. `Self` / `Rhs`, rounded towards zero.
+    type Quot: NumType;
+}
+impl<A, B> Arith<B> for A where A: Add<B> + Sub<B> + Mul<B> + Div<B> {
+    type Sum = <A as Add<B>>::Out;
+    type Diff = <A as Sub<B>>::Out;
+    type Prod = <A as Mul<B>>::Out;
+    type Quot = <A as Div<B>>::Out;
+}
 
-/// Shorthand for the number –1 (the first predecessor of zero).
-pub type N1 = Pred<Zero>;    
-/// Shorthand for the number –2 (the second predecessor of zero).
-pub type N2 = Pred<N1>;      
-/// Shorthand for the number –3 (the third predecessor of zero).
-pub type N3 = Pred<N2>;      
-/// Shorthand for the number –4 (the fourth predecessor of zero).
-pub type N4 = Pred<N3>;      
-/// Shorthand for the number –5 (the fifth predecessor of zero).
-pub type N5 = Pred<N4>;      
-/// Shorthand for the number –6 (the sixth predecessor of zero).
-pub type N6 = Pred<N5>;      
-/// Shorthand for the number –7 (the seventh predecessor of zero).
-pub type N7 = Pred<N6>;      
-/// Shorthand for the number –8 (the eight predecessor of zero).
-pub type N8 = Pred<N7>;     
-/// Shorthand for the number –9 (the ninth predecessor of zero).
-pub type N9 = Pred<N8>;
+/// Umbrella trait for the operations on a single number, the counterpart of `Arith`.
+pub trait Unary: NumType {
+    /// The negation, i.e. –`Self`.
+    type Negation: NumType;
+    /// The absolute value of `Self`.
+    type AbsVal: PosType;
+}
+impl<A, P: PosType> Unary for A where A: Neg + Abs<Out=P> {
+    type Negation = <A as Neg>::Out;
+    type AbsVal = P;
+}
 
-type Plus5<N> = Succ<Succ<Succ<Succ<Succ<N>>>>>;
-type Plus10<N> = Plus5<Plus5<N>>;
-type Plus50<N> = Plus10<Plus10<Plus10<Plus10<Plus10<N>>>>>;
+/// Remainder of the division of number types, which has the sign of `Self` (like `%` for
+/// integers in Rust), so that `Self` = (`Self` / `RHS`) * `RHS` + `Self` % `RHS`.
+#[diagnostic::on_unimplemented(
+    message = "cannot compute the remainder of `{Self}` divided by `{RHS}` at the type level",
+    note = "division by `Zero` is not defined, and both operands must be canonical number types"
+)]
+pub trait Rem<RHS: NonZero>: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` % `RHS`.
+    type Out: NumType;
+}
+impl<A, RHS: NonZero, AA: PosType, BB: PosType, R, S> Rem<RHS> for A
+    where A: Abs<Out=AA> + Sign<Out=S>, RHS: Abs<Out=BB>, AA: DivRem<BB, Rem=R>, S: RemSign<R> { type Out = S::Out; }
 
-#[test]
-fn zero_sized() {
-    use std::mem::size_of;
-    assert_eq!(size_of::<Zero>(), 0);
-    assert_eq!(size_of::<P1>(), 0);
-    assert_eq!(size_of::<N1>(), 0);
-    assert_eq!(size_of::<Plus50<Zero>>(), 0);
+/// Applies the sign of the dividend to the remainder of the magnitudes, used to implement `Rem`.
+#[doc(hidden)]
+pub trait RemSign<R>: SignType {
+    type Out: NumType;
 }
+impl<R: NumType> RemSign<R> for Positive { type Out = R; }
+impl<R: NumType> RemSign<R> for Neutral { type Out = R; }
+impl<R: Neg> RemSign<R> for Negative { type Out = R::Out; }
 
-#[test]
-fn into_number() {
-    assert_eq!(0, Zero::new().into());
-    assert_eq!(-3, N3::new().into());
-    assert_eq!(2, P2::new().into());
-    assert_eq!(2i8, P2::new().into());
-    assert_eq!(2u64, P2::new().into());
-    assert_eq!(2u8, P2::new().into());
-    
-    // 63 seems to be the maximal nesting depth acceptable to the compiler
-    type P63 = Plus10<Plus50<P3>>;
-    assert_eq!(63, P63::new().into());
+/// Marker trait for numbers that are a multiple of `D`, i.e. for which `Self` % `D` is zero.
+///
+/// ```
+/// use tylar::{IsDivisibleBy, NumType, P4};
+///
+/// fn aligned<N: NumType + IsDivisibleBy<P4>>() -> i64 { N::VALUE }
+///
+/// assert_eq!(aligned::<tylar::P8>(), 8);
+/// ```
+///
+/// Numbers that are not a multiple of `D` are rejected:
+///
+/// ```compile_fail
+/// use tylar::{IsDivisibleBy, NumType, P4, P6};
+///
+/// fn aligned<N: NumType + IsDivisibleBy<P4>>() -> i64 { N::VALUE }
+///
+/// aligned::<P6>();
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not divisible by `{D}`",
+    label = "expected a multiple of `{D}`"
+)]
+pub trait IsDivisibleBy<D: NonZero>: NumType {}
+impl<A, D: NonZero, R: RemIsZero> IsDivisibleBy<D> for A where A: Rem<D, Out=R> {}
+
+/// Checks that the remainder is zero, used to implement `IsDivisibleBy`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "number is not divisible, the remainder is `{Self}`",
+    label = "not divisible"
+)]
+pub trait RemIsZero {}
+impl RemIsZero for Zero {}
+
+/// Modular exponentiation of number types, i.e. `Out` = `Self` ^ `Exp` % `Mod`, for a positive
+/// (or zero) base. The remainder is taken after each multiplication, so the intermediate results
+/// stay below `Mod` * `Mod` no matter how large the exponent is.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{PowMod, P1, P3, P4, P5};
+///
+/// # fn main() {
+/// type_eq!(<P3 as PowMod<P4, P5>>::Out, P1); // 81 % 5 = 1
+/// # }
+/// ```
+pub trait PowMod<Exp: PosType, Mod: NonZero>: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` ^ `Exp` % `Mod`.
+    type Out: PosType;
 }
+impl<B: PosType, M: NonZero, R: PosType> PowMod<Zero, M> for B where Succ<Zero>: Rem<M, Out=R> { type Out = R; }
+impl<B: PosType, E: PosType, M: NonZero, X: PosType, BR, Y, R: PosType> PowMod<Succ<E>, M> for B
+    where B: PowMod<E, M, Out=X> + Rem<M, Out=BR>, X: Mul<BR, Out=Y>, Y: Rem<M, Out=R> { type Out = R; }
 
-#[test]
-fn operations() {
-    fn neg<A: NumType, Out: NumType>() -> i32 where A: Neg<Out=Out> {
-        Out::new().into()
-    }
-    
-    fn add<A: NumType, B: NumType, Out: NumType>() -> i32 where A: Add<B, Out=Out> {
-        Out::new().into()
-    }
-    
-    fn sub<A: NumType, B: NumType, Out: NumType>() -> i32 where A: Sub<B, Out=Out> {
-        Out::new().into()
-    }
-    
-    fn halve<A: NumType, Out: NumType>() -> i32 where A: Halve<Out=Out> {
-        Out::new().into()
-    }
-    
-    assert_eq!(-5, neg::<P5,_>());
+/// Greatest common divisor of number types, which is always positive (or zero, if both
+/// numbers are zero), computed with the Euclidean algorithm.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{Gcd, P4, P6, N9, P3};
+///
+/// # fn main() {
+/// type_eq!(<P4 as Gcd<P6>>::Out, tylar::P2);
+/// type_eq!(<N9 as Gcd<P6>>::Out, P3);
+/// # }
+/// ```
+pub trait Gcd<RHS>: NumType {
+    /// Result of the operation.
+    type Out: PosType;
+}
+impl<A: NumType, B, G: PosType> Gcd<B> for A where B: GcdStep<A, Out=G> { type Out = G; }
+
+/// One step of the Euclidean algorithm for `Gcd`, i.e. gcd(`A`, `Self`) = gcd(`Self`, `A` % `Self`),
+/// selected by `Self`, until `Self` is `Zero`.
+#[doc(hidden)]
+pub trait GcdStep<A> {
+    type Out: PosType;
+}
+impl<A: Abs> GcdStep<A> for Zero { type Out = A::Out; }
+impl<A, B: PosType, R, G: PosType> GcdStep<A> for Succ<B> where A: Rem<Succ<B>, Out=R>, R: GcdStep<Succ<B>, Out=G> { type Out = G; }
+impl<A, B: NegType, R, G: PosType> GcdStep<A> for Pred<B> where A: Rem<Pred<B>, Out=R>, R: GcdStep<Pred<B>, Out=G> { type Out = G; }
+
+/// Reduction of the fraction `Self` / `D` by the greatest common divisor of `Self` and `D`,
+/// e.g. to 1 / 2 for 4 / 8. The sign of the fraction is moved to the numerator, so that the
+/// denominator is always positive.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{Simplify, P1, P2, P4, P8};
+///
+/// # fn main() {
+/// type_eq!(<P4 as Simplify<P8>>::OutNum, P1);
+/// type_eq!(<P4 as Simplify<P8>>::OutDen, P2);
+/// # }
+/// ```
+pub trait Simplify<D: NonZero>: NumType {
+    /// The reduced numerator.
+    type OutNum: NumType;
+    /// The reduced denominator, which is strictly positive.
+    type OutDen: PosType;
+}
+impl<A, D: NonZero, G, AD, S, Q, N: NumType, R: PosType> Simplify<D> for A
+    where A: Gcd<D, Out=G> + Div<G, Out=Q>, D: Abs<Out=AD> + Div<AD, Out=S>, Q: Mul<S, Out=N>, AD: Div<G, Out=R>
+{
+    type OutNum = N;
+    type OutDen = R;
+}
+
+/// Division with remainder of a positive number (including zero) by a strictly positive
+/// number, used to implement `Div`.
+#[doc(hidden)]
+pub trait DivRem<RHS>: NumType {
+    /// Quotient, i.e. `Quot` = `Self` / `RHS`.
+    type Quot: NumType;
+    /// Remainder, i.e. `Rem` = `Self` – `Quot` * `RHS`.
+    type Rem: NumType;
+}
+impl<A: PosType, B, D> DivRem<B> for A where A: Sub<B, Out=D>, D: DivRemStep<A, B> {
+    type Quot = D::Quot;
+    type Rem = D::Rem;
+}
+
+/// One step of the long division, selected by the sign of `Self` = `A` – `B`.
+#[doc(hidden)]
+pub trait DivRemStep<A, B> {
+    type Quot: NumType;
+    type Rem: NumType;
+}
+// A < B, so the divisor doesn't fit at all
+impl<N, A: NumType, B> DivRemStep<A, B> for Pred<N> {
+    type Quot = Zero;
+    type Rem = A;
+}
+// A = B, so try the doubled divisor and fix up the result
+impl<A, B, BB, Q, R, D> DivRemStep<A, B> for Zero
+    where B: Add<B, Out=BB>, A: DivRem<BB, Quot=Q, Rem=R>, R: Sub<B, Out=D>, D: DivRemFixup<Q, R> {
+    type Quot = D::Quot;
+    type Rem = D::Rem;
+}
+// A > B, same as above
+impl<N: PosType, A, B, BB, Q, R, D> DivRemStep<A, B> for Succ<N>
+    where B: Add<B, Out=BB>, A: DivRem<BB, Quot=Q, Rem=R>, R: Sub<B, Out=D>, D: DivRemFixup<Q, R> {
+    type Quot = D::Quot;
+    type Rem = D::Rem;
+}
+
+/// Computes the next bit of the quotient, selected by the sign of `Self` = `R` – `B`,
+/// where `Q` and `R` are the quotient and remainder for the doubled divisor `B` + `B`.
+#[doc(hidden)]
+pub trait DivRemFixup<Q, R> {
+    type Quot: NumType;
+    type Rem: NumType;
+}
+// R < B, so the remainder stays and the next bit is zero
+impl<N, Q, R: NumType, QQ: NumType> DivRemFixup<Q, R> for Pred<N> where Q: Add<Q, Out=QQ> {
+    type Quot = QQ;
+    type Rem = R;
+}
+// R >= B, so B is subtracted from the remainder and the next bit is one
+impl<Q, R, QQ: PosType> DivRemFixup<Q, R> for Zero where Q: Add<Q, Out=QQ> {
+    type Quot = Succ<QQ>;
+    type Rem = Zero;
+}
+impl<N: PosType, Q, R, QQ: PosType> DivRemFixup<Q, R> for Succ<N> where Q: Add<Q, Out=QQ> {
+    type Quot = Succ<QQ>;
+    type Rem = Succ<N>;
+}
+
+/// Largest power of two that is less than or equal to a strictly positive number.
+pub trait BitFloor: PosType {
+    /// Result of the operation, e.g. `P4` for `P5` and `P8` for `P8`.
+    type Out: PosType;
+}
+impl BitFloor for Succ<Zero> { type Out = Succ<Zero>; }
+impl<A: PosType, H, F, D: PosType> BitFloor for Succ<Succ<A>>
+    where Succ<Succ<A>>: Div<Succ<Succ<Zero>>, Out=H>, H: BitFloor<Out=F>, F: Add<F, Out=D> { type Out = D; }
+
+/// Smallest power of two that is greater than or equal to a strictly positive number.
+///
+/// `P1` maps to itself (2⁰). `Zero` is rejected, like for `BitFloor`, because there is no
+/// impl for it. Every other input is computed as twice the bit-floor of its predecessor.
+pub trait BitCeil: PosType {
+    /// Result of the operation, e.g. `P8` for `P5` and `P8` for `P8`.
+    type Out: PosType;
+}
+impl BitCeil for Succ<Zero> { type Out = Succ<Zero>; }
+impl<A: PosType, F, D: PosType> BitCeil for Succ<Succ<A>>
+    where Succ<A>: BitFloor<Out=F>, F: Add<F, Out=D> { type Out = D; }
+
+/// A type-level function, i.e. a type that maps the argument type `Arg` to `Out`.
+/// This allows to pass operations as parameters to other type-level code, like `ApplyN`.
+///
+/// The number operations are available as functions like `IncrFn`, and new functions can
+/// be defined by implementing this trait for a new (usually zero-sized) type. Functions of
+/// two arguments take them as a tuple, like `AddFn`, which implements `TyFn<(A, B)>`, and
+/// predicates return `True` or `False`, like `IsPosPred`. See `list` for their use on lists.
+pub trait TyFn<Arg> {
+    /// Result of applying the function to `Arg`.
+    type Out;
+}
+
+/// Applies the type-level function `F` to `Self` `N` times.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{ApplyN, DoubleFn, P1, P3, P8};
+///
+/// # fn main() {
+/// type_eq!(<P1 as ApplyN<DoubleFn, P3>>::Out, P8);
+/// # }
+/// ```
+pub trait ApplyN<F, N: PosType> {
+    /// Result of the operation, i.e. `F(F(...F(Self)))` with `N` applications of `F`.
+    type Out;
+}
+impl<S, F> ApplyN<F, Zero> for S { type Out = S; }
+impl<S, F, N: PosType, X> ApplyN<F, Succ<N>> for S where S: ApplyN<F, N, Out=X>, F: TyFn<X> { type Out = F::Out; }
+
+/// The type-level function for `Incr`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct IncrFn;
+impl<A: Incr> TyFn<A> for IncrFn { type Out = A::Out; }
+
+/// The type-level function for `Decr`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct DecrFn;
+impl<A: Decr> TyFn<A> for DecrFn { type Out = A::Out; }
+
+/// The type-level function for `Double`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct DoubleFn;
+impl<A: Double> TyFn<A> for DoubleFn { type Out = A::Out; }
+
+/// The type-level function for `Neg`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct NegFn;
+impl<A: Neg> TyFn<A> for NegFn { type Out = A::Out; }
+
+/// The type-level function for `Add`, taking the operands as a tuple `(A, B)`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct AddFn;
+impl<A: Add<B>, B> TyFn<(A, B)> for AddFn { type Out = A::Out; }
+
+/// The type-level function for `Mul`, taking the operands as a tuple `(A, B)`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct MulFn;
+impl<A: Mul<B>, B> TyFn<(A, B)> for MulFn { type Out = A::Out; }
+
+/// The type-level predicate that is `True` for positive numbers (or zero), like `PosType`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct IsPosPred;
+impl TyFn<Zero> for IsPosPred { type Out = True; }
+impl<N: PosType> TyFn<Succ<N>> for IsPosPred { type Out = True; }
+impl<N: NegType> TyFn<Pred<N>> for IsPosPred { type Out = False; }
+
+/// The type-level predicate that is `True` for negative numbers (or zero), like `NegType`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct IsNegPred;
+impl TyFn<Zero> for IsNegPred { type Out = True; }
+impl<N: PosType> TyFn<Succ<N>> for IsNegPred { type Out = False; }
+impl<N: NegType> TyFn<Pred<N>> for IsNegPred { type Out = True; }
+
+/// Marker trait for the type-level booleans `True` and `False`. This trait is sealed.
+pub trait BoolType: private::Sealed {}
+
+/// The type-level boolean `true`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct True;
+
+/// The type-level boolean `false`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct False;
+
+impl private::Sealed for True {}
+impl private::Sealed for False {}
+
+impl BoolType for True {}
+impl BoolType for False {}
+
+/// Type-level conditional, selecting `Then` if `Self` is `True` and `Else` if it is `False`.
+pub trait If<Then, Else>: BoolType {
+    /// Result of the operation, i.e. either `Then` or `Else`.
+    type Out;
+}
+impl<Then, Else> If<Then, Else> for True { type Out = Then; }
+impl<Then, Else> If<Then, Else> for False { type Out = Else; }
+
+/// Parity of number types, as a type-level boolean which is `True` for even numbers
+/// and `False` for odd numbers.
+pub trait Parity: NumType {
+    /// Result of the operation, i.e. whether `Self` is even.
+    type Out: BoolType;
+}
+impl Parity for Zero { type Out = True; }
+impl Parity for Succ<Zero> { type Out = False; }
+impl Parity for Pred<Zero> { type Out = False; }
+impl<A: PosType> Parity for Succ<Succ<A>> where A: Parity { type Out = A::Out; }
+impl<A: NegType> Parity for Pred<Pred<A>> where A: Parity { type Out = A::Out; }
+
+/// Marker trait for the results of `Compare`, i.e. `Less`, `Equal` and `Greater`.
+/// This trait is sealed.
+pub trait OrderingType: private::Sealed {}
+
+/// The result of comparing a number to a larger one.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct Less;
+
+/// The result of comparing two equal numbers.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct Equal;
+
+/// The result of comparing a number to a smaller one.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord)]
+pub struct Greater;
+
+impl private::Sealed for Less {}
+impl private::Sealed for Equal {}
+impl private::Sealed for Greater {}
+
+impl OrderingType for Less {}
+impl OrderingType for Equal {}
+impl OrderingType for Greater {}
+
+/// Comparison of number types.
+#[diagnostic::on_unimplemented(
+    message = "cannot compare `{Self}` to `{RHS}` at the type level",
+    note = "both operands must be canonical number types (see `Normalize`)"
+)]
+pub trait Compare<RHS>: NumType {
+    /// Result of the operation, i.e. `Less`, `Equal` or `Greater`.
+    type Out: OrderingType;
+}
+impl<A, RHS, D, S> Compare<RHS> for A where A: Sub<RHS, Out=D>, D: Sign<Out=S>, S: SignToOrdering { type Out = S::Out; }
+
+/// Maps the sign of `Self` – `RHS` to the result of `Compare`.
+#[doc(hidden)]
+pub trait SignToOrdering: SignType {
+    type Out: OrderingType;
+}
+impl SignToOrdering for Negative { type Out = Less; }
+impl SignToOrdering for Neutral { type Out = Equal; }
+impl SignToOrdering for Positive { type Out = Greater; }
+
+/// The smaller of two numbers, e.g. `N2` for `P3` and `N2`.
+pub trait Min<RHS>: NumType {
+    /// Result of the operation.
+    type Out: NumType;
+}
+impl<A, RHS, O> Min<RHS> for A where A: Compare<RHS, Out=O>, O: SelectMin<A, RHS> { type Out = O::Out; }
+
+/// The larger of two numbers, e.g. `P3` for `P3` and `N2`.
+pub trait Max<RHS>: NumType {
+    /// Result of the operation.
+    type Out: NumType;
+}
+impl<A, RHS, O> Max<RHS> for A where A: Compare<RHS, Out=O>, O: SelectMax<A, RHS> { type Out = O::Out; }
+
+/// Selects the smaller of `A` and `B` by the result of comparing them, used to implement `Min`.
+#[doc(hidden)]
+pub trait SelectMin<A, B>: OrderingType {
+    type Out: NumType;
+}
+impl<A: NumType, B> SelectMin<A, B> for Less { type Out = A; }
+impl<A: NumType, B> SelectMin<A, B> for Equal { type Out = A; }
+impl<A, B: NumType> SelectMin<A, B> for Greater { type Out = B; }
+
+/// Selects the larger of `A` and `B` by the result of comparing them, used to implement `Max`.
+#[doc(hidden)]
+pub trait SelectMax<A, B>: OrderingType {
+    type Out: NumType;
+}
+impl<A, B: NumType> SelectMax<A, B> for Less { type Out = B; }
+impl<A: NumType, B> SelectMax<A, B> for Equal { type Out = A; }
+impl<A: NumType, B> SelectMax<A, B> for Greater { type Out = A; }
+
+/// Marker trait for numbers that are less than or equal to `RHS`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not less than or equal to `{RHS}`",
+    label = "expected a number less than or equal to `{RHS}`"
+)]
+pub trait LessEq<RHS>: NumType {}
+impl<A: NumType, RHS, D: PosType> LessEq<RHS> for A where RHS: Sub<A, Out=D> {}
+
+/// Addition of number types that is only implemented if the magnitude of the result
+/// doesn't exceed `Max`.
+///
+/// Computations that are too large for the typechecker usually fail with an overflow
+/// of the recursion limit, deep inside the implementation of the operations. Bounded
+/// operations allow to fail early with an ordinary error about the unsatisfied bound:
+///
+/// ```compile_fail
+/// use tylar::{NumType, BoundedAdd, P5, P6, P9};
+///
+/// // error[E0277]: the magnitude `Succ<...>` of the result of a bounded operation exceeds `Succ<...>`
+/// // note: required for `Succ<...>` to implement `BoundedAdd<Succ<...>, Succ<...>>`
+/// let x: i32 = <P5 as BoundedAdd<P6, P9>>::Out::new().into();
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "the magnitude of `{Self}` + `{RHS}` exceeds `{Max}`",
+)]
+pub trait BoundedAdd<RHS, Max>: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` + `RHS`.
+    type Out: NumType;
+}
+impl<A, RHS, Max, S, M, O> BoundedAdd<RHS, Max> for A
+    where A: Add<RHS, Out=S>, S: Abs<Out=M>, M: Compare<Max, Out=O>, O: WithinBound<M, Max> { type Out = S; }
+
+/// Multiplication of number types that is only implemented if the magnitude of the
+/// result doesn't exceed `Max` (see `BoundedAdd`).
+#[diagnostic::on_unimplemented(
+    message = "the magnitude of `{Self}` * `{RHS}` exceeds `{Max}`",
+)]
+pub trait BoundedMul<RHS, Max>: NumType {
+    /// Result of the operation, i.e. `Out` = `Self` * `RHS`.
+    type Out: NumType;
+}
+impl<A, RHS, Max, P, M, O> BoundedMul<RHS, Max> for A
+    where A: Mul<RHS, Out=P>, P: Abs<Out=M>, M: Compare<Max, Out=O>, O: WithinBound<M, Max> { type Out = P; }
+
+/// Accepts the ordering of the magnitude `N` of a result relative to the bound `Max`,
+/// used to implement `BoundedAdd` and `BoundedMul`. Only implemented for `Less` and
+/// `Equal`, so that an exceeded bound is reported by this trait's diagnostic.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the magnitude `{N}` of the result of a bounded operation exceeds `{Max}`",
+    label = "bound exceeded here",
+    note = "use a larger `Max`, or `checked::CheckedAdd` to handle the overflow at the type level"
+)]
+pub trait WithinBound<N, Max>: OrderingType {}
+impl<N, Max> WithinBound<N, Max> for Less {}
+impl<N, Max> WithinBound<N, Max> for Equal {}
+
+/// Returns the value of a number type, i.e. `T::VALUE`, in a `const fn` that can be
+/// called in constant expressions such as array lengths.
+///
+/// ```
+/// use tylar::{value_of, P8};
+///
+/// let buf = [0u8; value_of::<P8>() as usize];
+/// assert_eq!(buf.len(), 8);
+/// ```
+#[inline(always)]
+pub const fn value_of<T: NumType>() -> i64 {
+    T::VALUE
+}
+
+/// Tuples of one to eight number types, whose values can be folded with `sum_values`
+/// and `product_values`.
+pub trait TupleOfNums {
+    /// The sum of the values of the elements.
+    const SUM: i64;
+    /// The product of the values of the elements.
+    const PRODUCT: i64;
+}
+
+macro_rules! impl_tuple_of_nums {
+    ($(($($t:ident),+))+) => {$(
+        impl<$($t: NumType),+> TupleOfNums for ($($t,)+) {
+            const SUM: i64 = 0 $(+ $t::VALUE)+;
+            const PRODUCT: i64 = 1 $(* $t::VALUE)+;
+        }
+    )+}
+}
+
+impl_tuple_of_nums!((A) (A, B) (A, B, C) (A, B, C, D) (A, B, C, D, E) (A, B, C, D, E, F)
+    (A, B, C, D, E, F, G) (A, B, C, D, E, F, G, H));
+
+/// Returns the sum of the values of a tuple of number types, e.g. 6 for `(P1, P2, P3)`.
+#[inline(always)]
+pub const fn sum_values<T: TupleOfNums>() -> i64 {
+    T::SUM
+}
+
+/// Returns the product of the values of a tuple of number types, e.g. 6 for `(P1, P2, P3)`.
+#[inline(always)]
+pub const fn product_values<T: TupleOfNums>() -> i64 {
+    T::PRODUCT
+}
+
+/// A `usize` constant as a type, for converting const generics into number types with
+/// `ToPeano`. The opposite direction is `PosType::USIZE`.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct Const<const N: usize>;
+
+/// Conversion of const generics into number types, e.g. `<Const<8> as ToPeano>::Peano` is `P8`.
+/// This is implemented for `Const<0>` up to `Const<120>` (see `MAX_PRACTICAL_DEPTH`).
+///
+/// Together with `PosType::USIZE`, this allows to move between the two worlds, e.g. to
+/// turn an array `[T; K]` into a `stack::TStack<T, N>` and back.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` exceeds `MAX_PRACTICAL_DEPTH`",
+    label = "no number type for this constant",
+    note = "constants and number literals must be at most `MAX_PRACTICAL_DEPTH` (120)"
+)]
+pub trait ToPeano {
+    /// The number type with the value of the constant.
+    type Peano: PosType;
+}
+impl ToPeano for Const<0> { type Peano = Zero; }
+
+macro_rules! impl_to_peano {
+    ($($n:literal $prev:literal),+) => {$(
+        impl ToPeano for Const<$n> { type Peano = Succ<<Const<$prev> as ToPeano>::Peano>; }
+    )+}
+}
+
+impl_to_peano!(1 0, 2 1, 3 2, 4 3, 5 4, 6 5, 7 6, 8 7, 9 8, 10 9, 11 10, 12 11, 13 12, 14 13, 15 14,
+    16 15, 17 16, 18 17, 19 18, 20 19, 21 20, 22 21, 23 22, 24 23, 25 24, 26 25, 27 26, 28 27,
+    29 28, 30 29, 31 30, 32 31, 33 32, 34 33, 35 34, 36 35, 37 36, 38 37, 39 38, 40 39, 41 40,
+    42 41, 43 42, 44 43, 45 44, 46 45, 47 46, 48 47, 49 48, 50 49, 51 50, 52 51, 53 52, 54 53,
+    55 54, 56 55, 57 56, 58 57, 59 58, 60 59, 61 60, 62 61, 63 62, 64 63, 65 64, 66 65, 67 66,
+    68 67, 69 68, 70 69, 71 70, 72 71, 73 72, 74 73, 75 74, 76 75, 77 76, 78 77, 79 78, 80 79,
+    81 80, 82 81, 83 82, 84 83, 85 84, 86 85, 87 86, 88 87, 89 88, 90 89, 91 90, 92 91, 93 92,
+    94 93, 95 94, 96 95, 97 96, 98 97, 99 98, 100 99, 101 100, 102 101, 103 102, 104 103, 105 104,
+    106 105, 107 106, 108 107, 109 108, 110 109, 111 110, 112 111, 113 112, 114 113, 115 114,
+    116 115, 117 116, 118 117, 119 118, 120 119);
+
+/// Returns an array of `K` default values, where `K` must be the value of the number type `N`.
+/// Since `K` is usually inferred, this allows to create arrays whose length is given by a
+/// number type in generic code:
+///
+/// ```
+/// use tylar::{make_array, Const, PosType, ToPeano, P4};
+///
+/// fn zeros<N: PosType, const K: usize>() -> [u8; K] where Const<K>: ToPeano<Peano=N> {
+///     make_array::<u8, N, K>()
+/// }
+///
+/// let buf: [u8; 4] = zeros::<P4, _>();
+/// assert_eq!(buf, [0; 4]);
+/// ```
+///
+/// A length that differs from the value of `N` doesn't compile:
+///
+/// ```compile_fail
+/// use tylar::{make_array, P4};
+///
+/// let buf: [u8; 5] = make_array::<u8, P4, _>();
+/// ```
+#[inline(always)]
+pub fn make_array<T: Default + Copy, N: PosType, const K: usize>() -> [T; K] where Const<K>: ToPeano<Peano=N> {
+    [T::default(); K]
+}
+
+/// Enums whose number of variants is given as a number type, e.g. to size a
+/// `stack::TStack` with one element per variant. With the `macros` feature, this can be
+/// derived with `#[derive(VariantCount)]`, which counts and indexes the variants in order
+/// of declaration, like this impl:
+///
+/// ```
+/// use tylar::{VariantCount, P3};
+///
+/// enum Axis { X, Y, Z }
+///
+/// impl VariantCount for Axis {
+///     type Count = P3;
+///     fn index(&self) -> usize {
+///         match *self { Axis::X => 0, Axis::Y => 1, Axis::Z => 2 }
+///     }
+/// }
+///
+/// assert_eq!(Axis::COUNT, 3);
+/// assert_eq!(Axis::Z.index(), 2);
+/// ```
+pub trait VariantCount {
+    /// The number of variants.
+    type Count: PosType;
+    /// The number of variants as `usize`.
+    const COUNT: usize = <Self::Count as PosType>::USIZE;
+    /// Returns the index of the variant, which is less than `COUNT`.
+    fn index(&self) -> usize;
+}
+
+/// Calls a closure once for each index in `0..N`, unrolled by recursion over the number type,
+/// which is used to implement `repeat`. This is implemented for all positive numbers.
+pub trait Unroll: PosType {
+    /// Calls `f` with the indices `0..Self`, in ascending order.
+    fn unroll<F: FnMut(usize)>(f: &mut F);
+}
+impl Unroll for Zero {
+    #[inline(always)] fn unroll<F: FnMut(usize)>(_: &mut F) {}
+}
+impl<N: Unroll> Unroll for Succ<N> {
+    #[inline(always)] fn unroll<F: FnMut(usize)>(f: &mut F) { N::unroll(f); f(N::USIZE) }
+}
+
+/// Calls `f` exactly `N` times, with the indices `0..N` in ascending order. Unlike a loop,
+/// the calls are unrolled at compile time, since each step is a separate (inlined) function.
+///
+/// ```
+/// use tylar::{repeat, P4};
+///
+/// let mut squares = Vec::new();
+/// repeat::<P4, _>(|i| squares.push(i * i));
+/// assert_eq!(squares, [0, 1, 4, 9]);
+/// ```
+#[inline(always)]
+pub fn repeat<N: Unroll, F: FnMut(usize)>(mut f: F) {
+    N::unroll(&mut f)
+}
+
+/// Returns an iterator over the values from `N` down to 1, e.g. `3, 2, 1` for `P3`, which
+/// is empty for `Zero`.
+///
+/// ```
+/// use tylar::{countdown, P3};
+///
+/// let steps: Vec<i64> = countdown::<P3>().collect();
+/// assert_eq!(steps, [3, 2, 1]);
+/// ```
+#[inline(always)]
+pub fn countdown<N: PosType>() -> impl Iterator<Item = i64> {
+    (1..=N::VALUE).rev()
+}
+
+/// Returns the value of a number type as `i128`, which only requires the `NumType` bound.
+#[inline(always)]
+pub fn as_i128<T: NumType>() -> i128 {
+    T::new().into()
+}
+
+/// Compares the values of two (possibly different) number types at runtime.
+#[inline(always)]
+pub fn cmp_values<A: NumType, B: NumType>() -> Ordering {
+    Into::<i64>::into(A::new()).cmp(&Into::<i64>::into(B::new()))
+}
+
+/// Returns whether the value of `A` is less than the value of `B`.
+#[inline(always)]
+pub fn lt<A: NumType, B: NumType>() -> bool { cmp_values::<A, B>() == Ordering::Less }
+
+/// Returns whether the value of `A` is less than or equal to the value of `B`.
+#[inline(always)]
+pub fn le<A: NumType, B: NumType>() -> bool { cmp_values::<A, B>() != Ordering::Greater }
+
+/// Returns whether the value of `A` is greater than the value of `B`.
+#[inline(always)]
+pub fn gt<A: NumType, B: NumType>() -> bool { cmp_values::<A, B>() == Ordering::Greater }
+
+/// Returns whether the value of `A` is greater than or equal to the value of `B`.
+#[inline(always)]
+pub fn ge<A: NumType, B: NumType>() -> bool { cmp_values::<A, B>() != Ordering::Less }
+
+/// The value of a number type at runtime, with the type erased, e.g. for printing or
+/// combining the results of differently parameterized generic code.
+///
+/// ```
+/// use tylar::{AnyNum, NumType, P3, N2};
+///
+/// let x = AnyNum::from(P3::new()) * AnyNum::from(N2::new());
+/// assert_eq!(x.value(), -6);
+/// assert!(AnyNum::from(P3::new()).matches::<P3>());
+/// ```
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Hash,Debug)]
+pub struct AnyNum(i64);
+
+impl AnyNum {
+    /// Returns the value.
+    #[inline(always)]
+    pub fn value(self) -> i64 { self.0 }
+
+    /// Returns whether the value equals the value of the number type `N`.
+    #[inline(always)]
+    pub fn matches<N: NumType>(self) -> bool { self.0 == N::VALUE }
+
+    /// Returns an instance of the number type `N` if the value equals its value.
+    #[inline(always)]
+    pub fn try_as<N: NumType>(self) -> Option<N> {
+        if self.matches::<N>() { Some(N::new()) } else { None }
+    }
+}
+
+impl<N: NumType> From<N> for AnyNum {
+    #[inline(always)]
+    fn from(_: N) -> AnyNum { AnyNum(N::VALUE) }
+}
+
+impl From<AnyNum> for i64 {
+    #[inline(always)]
+    fn from(n: AnyNum) -> i64 { n.0 }
+}
+
+impl fmt::Display for AnyNum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+macro_rules! impl_any_num_ops {
+    ($($op:ident $method:ident),+) => {$(
+        impl core::ops::$op for AnyNum {
+            type Output = AnyNum;
+            #[inline(always)] fn $method(self, rhs: AnyNum) -> AnyNum { AnyNum(core::ops::$op::$method(self.0, rhs.0)) }
+        }
+    )+}
+}
+
+impl_any_num_ops!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl core::ops::Neg for AnyNum {
+    type Output = AnyNum;
+    #[inline(always)] fn neg(self) -> AnyNum { AnyNum(-self.0) }
+}
+
+/// An object-safe view of an instance of a number type, so that instances of different
+/// number types can be stored together behind `dyn NumValue`.
+///
+/// ```
+/// use tylar::{NumType, NumValue, P2, N3, Zero};
+///
+/// let nums: Vec<Box<dyn NumValue>> = vec![Box::new(P2::new()), Box::new(N3::new()), Box::new(Zero::new())];
+/// assert_eq!(nums.iter().map(|n| n.value()).sum::<i64>(), -1);
+/// ```
+pub trait NumValue {
+    /// Returns the value of the number type.
+    fn value(&self) -> i64;
+
+    /// Returns the sign of the value as an `Ordering` relative to zero.
+    fn sign(&self) -> Ordering;
+}
+
+impl<N: NumType> NumValue for N {
+    #[inline(always)]
+    fn value(&self) -> i64 { N::VALUE }
+
+    #[inline(always)]
+    fn sign(&self) -> Ordering { N::VALUE.cmp(&0) }
+}
+
+/// Counts at the type level: every call of `incr` or `decr` changes the type parameter `N`,
+/// so the final count of a chain of calls is known at compile time.
+///
+/// ```
+/// use tylar::{Counter, P2};
+///
+/// let c = Counter::new().incr().incr().incr().decr();
+/// let _: Counter<P2> = c;
+/// assert_eq!(c.total(), 2);
+/// ```
+#[derive(Copy,Clone,PartialEq,Eq,Debug)]
+pub struct Counter<N: NumType> {
+    p: PhantomData<N>
+}
+
+impl Counter<Zero> {
+    /// Creates a new counter starting at `Zero`.
+    #[inline(always)]
+    pub const fn new() -> Self { Counter { p: PhantomData } }
+}
+
+impl<N: NumType> Counter<N> {
+    /// Returns a counter whose count is one more than this one's.
+    #[inline(always)]
+    pub fn incr(self) -> Counter<<N as Incr>::Out> where N: Incr { Counter { p: PhantomData } }
+
+    /// Returns a counter whose count is one less than this one's.
+    #[inline(always)]
+    pub fn decr(self) -> Counter<<N as Decr>::Out> where N: Decr { Counter { p: PhantomData } }
+
+    /// Returns the count, i.e. the value of `N`.
+    #[inline(always)]
+    pub const fn total(self) -> i64 { N::VALUE }
+}
+
+impl Default for Counter<Zero> {
+    fn default() -> Self { Self::new() }
+}
+
+/// Value-level operators on instances of number types.
+///
+/// The number types implement the operator traits `Add`, `Sub`, `Mul`, `Div` and `Neg`
+/// from `std::ops` in terms of the type-level operations of the same names, so instances
+/// can be combined in ordinary expressions and the result type is inferred:
+///
+/// ```
+/// use tylar::{NumType, P2, P3, N4};
+///
+/// let x = (P2::new() + P3::new()) * N4::new() - P3::new();
+/// let v: i32 = x.into();
+/// assert_eq!(v, -23);
+/// ```
+///
+/// The operators themselves never need an import. Note that the type-level traits in the
+/// crate root share their names with the ones in `std::ops`, so bring at most one of the
+/// two sets into scope under the plain names (or rename on import).
+pub mod ops {
+    use super::{Zero, Succ, Pred, NumType};
+
+    macro_rules! impl_ops {
+        ($([$($param:ident),*] $ty:ty),*) => {$(
+            impl<$($param,)* RHS> ::core::ops::Add<RHS> for $ty where $ty: super::Add<RHS> {
+                type Output = <$ty as super::Add<RHS>>::Out;
+                #[inline(always)] fn add(self, _: RHS) -> Self::Output { NumType::new() }
+            }
+            impl<$($param,)* RHS> ::core::ops::Sub<RHS> for $ty where $ty: super::Sub<RHS> {
+                type Output = <$ty as super::Sub<RHS>>::Out;
+                #[inline(always)] fn sub(self, _: RHS) -> Self::Output { NumType::new() }
+            }
+            impl<$($param,)* RHS> ::core::ops::Mul<RHS> for $ty where $ty: super::Mul<RHS> {
+                type Output = <$ty as super::Mul<RHS>>::Out;
+                #[inline(always)] fn mul(self, _: RHS) -> Self::Output { NumType::new() }
+            }
+            impl<$($param,)* RHS> ::core::ops::Div<RHS> for $ty where $ty: super::Div<RHS> {
+                type Output = <$ty as super::Div<RHS>>::Out;
+                #[inline(always)] fn div(self, _: RHS) -> Self::Output { NumType::new() }
+            }
+            impl<$($param),*> ::core::ops::Neg for $ty where $ty: super::Neg {
+                type Output = <$ty as super::Neg>::Out;
+                #[inline(always)] fn neg(self) -> Self::Output { NumType::new() }
+            }
+        )*}
+    }
+
+    impl_ops!([] Zero, [N] Succ<N>, [N] Pred<N>);
+}
+
+/// Free functions for the type-level operations, which return an instance of the result.
+///
+/// The result type is given by the last type parameter, which can always be inferred,
+/// so the result can be converted with `.into()` right away:
+///
+/// ```
+/// use tylar::fns::{add, mul};
+/// use tylar::{P2, P3, N2, P5};
+///
+/// let x: i32 = add::<N2, P5, _>().into();
+/// assert_eq!(x, 3);
+/// let y: P5 = add::<P2, P3, _>();
+/// assert_eq!(mul::<P3, N2, _>(), -6);
+/// # let _ = y;
+/// ```
+pub mod fns {
+    use super::{NumType, Add, Sub, Mul, Div, Neg, Halve};
+
+    /// Returns an instance of `A` + `B`.
+    #[inline(always)]
+    pub fn add<A: NumType + Add<B, Out=Out>, B: NumType, Out: NumType>() -> Out { Out::new() }
+
+    /// Returns an instance of `A` – `B`.
+    #[inline(always)]
+    pub fn sub<A: NumType + Sub<B, Out=Out>, B: NumType, Out: NumType>() -> Out { Out::new() }
+
+    /// Returns an instance of `A` * `B`.
+    #[inline(always)]
+    pub fn mul<A: NumType + Mul<B, Out=Out>, B: NumType, Out: NumType>() -> Out { Out::new() }
+
+    /// Returns an instance of `A` / `B`.
+    #[inline(always)]
+    pub fn div<A: NumType + Div<B, Out=Out>, B: NumType, Out: NumType>() -> Out { Out::new() }
+
+    /// Returns an instance of –`A`.
+    #[inline(always)]
+    pub fn neg<A: NumType + Neg<Out=Out>, Out: NumType>() -> Out { Out::new() }
+
+    /// Returns an instance of `A` / 2.
+    #[inline(always)]
+    pub fn halve<A: NumType + Halve<Out=Out>, Out: NumType>() -> Out { Out::new() }
+}
+
+/// Shorthand for the number 1 (the first successor of zero).
+pub type P1 = Succ<Zero>;
+/// Shorthand for the number 2 (the second successor of zero).
+pub type P2 = Succ<P1>;
+/// Shorthand for the number 3 (the third successor of zero).
+pub type P3 = Succ<P2>;
+/// Shorthand for the number 4 (the fourth successor of zero).
+pub type P4 = Succ<P3>;
+/// Shorthand for the number 5 (the fifth successor of zero).
+pub type P5 = Succ<P4>;
+/// Shorthand for the number 6 (the sixth successor of zero).
+pub type P6 = Succ<P5>;
+/// Shorthand for the number 7 (the seventh successor of zero).
+pub type P7 = Succ<P6>;
+/// Shorthand for the number 8 (the eighth successor of zero).
+pub type P8 = Succ<P7>;
+/// Shorthand for the number 9 (the nineth successor of zero).
+pub type P9 = Succ<P8>;
+
+/// Shorthand for the number –1 (the first predecessor of zero).
+pub type N1 = Pred<Zero>;    
+/// Shorthand for the number –2 (the second predecessor of zero).
+pub type N2 = Pred<N1>;      
+/// Shorthand for the number –3 (the third predecessor of zero).
+pub type N3 = Pred<N2>;      
+/// Shorthand for the number –4 (the fourth predecessor of zero).
+pub type N4 = Pred<N3>;      
+/// Shorthand for the number –5 (the fifth predecessor of zero).
+pub type N5 = Pred<N4>;      
+/// Shorthand for the number –6 (the sixth predecessor of zero).
+pub type N6 = Pred<N5>;      
+/// Shorthand for the number –7 (the seventh predecessor of zero).
+pub type N7 = Pred<N6>;      
+/// Shorthand for the number –8 (the eight predecessor of zero).
+pub type N8 = Pred<N7>;     
+/// Shorthand for the number –9 (the ninth predecessor of zero).
+pub type N9 = Pred<N8>;
+
+/// Building block for larger positive numbers, adding 5 to the positive number `N`.
+///
+/// The building blocks can be composed to write down any number, e.g. `Plus10<Plus50<P3>>`
+/// for 63 and `Minus100<Minus10<N2>>` for –112. The argument must have the same sign as
+/// the block (or be `Zero`), otherwise the result is not a canonical number type. How large
+/// the numbers can get depends on the `recursion_limit` of the crate that uses them (see
+/// also `MAX_PRACTICAL_DEPTH`), for conversions and simple operations like `Add` and `Neg`:
+///
+/// | `recursion_limit` | largest magnitude |
+/// |-------------------|-------------------|
+/// | 64                | 60                |
+/// | 128 (default)     | 124               |
+/// | 256               | 252               |
+/// | 512               | 508               |
+///
+/// `Div` needs about twice the magnitude of the dividend, and `Mul` the magnitude of the
+/// product. Beyond the limit, the compiler refuses with an "overflow evaluating the
+/// requirement" error, so there are no building blocks larger than `Plus100`/`Minus100`.
+pub type Plus5<N> = Succ<Succ<Succ<Succ<Succ<N>>>>>;
+/// Building block for larger positive numbers, adding 10 to the positive number `N`.
+pub type Plus10<N> = Plus5<Plus5<N>>;
+/// Building block for larger positive numbers, adding 50 to the positive number `N`.
+pub type Plus50<N> = Plus10<Plus10<Plus10<Plus10<Plus10<N>>>>>;
+/// Building block for larger positive numbers, adding 100 to the positive number `N`.
+pub type Plus100<N> = Plus50<Plus50<N>>;
+
+/// Building block for larger negative numbers, subtracting 5 from the negative number `N`
+/// (see `Plus5` for how to compose the building blocks).
+pub type Minus5<N> = Pred<Pred<Pred<Pred<Pred<N>>>>>;
+/// Building block for larger negative numbers, subtracting 10 from the negative number `N`.
+pub type Minus10<N> = Minus5<Minus5<N>>;
+/// Building block for larger negative numbers, subtracting 50 from the negative number `N`.
+pub type Minus50<N> = Minus10<Minus10<Minus10<Minus10<Minus10<N>>>>>;
+/// Building block for larger negative numbers, subtracting 100 from the negative number `N`.
+pub type Minus100<N> = Minus50<Minus50<N>>;
+
+/// The largest magnitude of numbers (including intermediate results) that can be used
+/// with the default `recursion_limit` of 128 (see the crate documentation).
+pub const MAX_PRACTICAL_DEPTH: usize = 120;
+
+/// The number type with the value `MAX_PRACTICAL_DEPTH`.
+pub type PMax = Plus100<Plus10<Plus10<Zero>>>;
+
+/// Type equality constraint: `A: Same<B>` holds if and only if `A` and `B` are the same type.
+pub trait Same<T: ?Sized> {}
+impl<T: ?Sized> Same<T> for T {}
+
+/// Helper function for `type_eq!`, which can only be called if `A` and `B` are the same type.
+#[inline(always)]
+pub fn assert_same<A: ?Sized + Same<B>, B: ?Sized>() {}
+
+/// Asserts at compile time that two types are identical (not just equal in value).
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{Add, P2, P3, P5};
+///
+/// fn main() {
+///     type_eq!(<P2 as Add<P3>>::Out, P5);
+/// }
+/// ```
+///
+/// Two different types are rejected by the typechecker:
+///
+/// ```compile_fail
+/// #[macro_use] extern crate tylar;
+/// use tylar::{Add, P2, P3, P6};
+///
+/// fn main() {
+///     type_eq!(<P2 as Add<P3>>::Out, P6);
+/// }
+/// ```
+#[macro_export]
+macro_rules! type_eq {
+    ($a:ty, $b:ty) => { $crate::assert_same::<$a, $b>() };
+}
+
+/// Invokes a block for the number type matching a runtime value, which must lie within
+/// `-16..=16`. The block is monomorphized for each number type in that range, with the
+/// given identifier as an alias for the number type. Evaluates to `Some` with the value
+/// of the block, or to `None` if the value is out of range.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::NumType;
+///
+/// fn main() {
+///     let n = 3;
+///     let squared = with_tynum!(n, |T| {
+///         let value: i32 = T::new().into();
+///         value * value
+///     });
+///     assert_eq!(squared, Some(9));
+///     assert_eq!(with_tynum!(17, |T| ()), None);
+/// }
+/// ```
+#[macro_export]
+macro_rules! with_tynum {
+    ($n:expr, |$t:ident| $body:expr) => {
+        $crate::with_tynum!(@arms ($n) as i64, $t, $body;
+            -16 => $crate::Pred<$crate::Pred<$crate::Pred<$crate::Pred<$crate::Pred<$crate::Pred<$crate::Pred<$crate::N9>>>>>>>,
+            -15 => $crate::Pred<$crate::Pred<$crate::Pred<$crate::Pred<$crate::Pred<$crate::Pred<$crate::N9>>>>>>,
+            -14 => $crate::Pred<$crate::Pred<$crate::Pred<$crate::Pred<$crate::Pred<$crate::N9>>>>>,
+            -13 => $crate::Pred<$crate::Pred<$crate::Pred<$crate::Pred<$crate::N9>>>>,
+            -12 => $crate::Pred<$crate::Pred<$crate::Pred<$crate::N9>>>,
+            -11 => $crate::Pred<$crate::Pred<$crate::N9>>,
+            -10 => $crate::Pred<$crate::N9>,
+            -9 => $crate::N9,
+            -8 => $crate::N8,
+            -7 => $crate::N7,
+            -6 => $crate::N6,
+            -5 => $crate::N5,
+            -4 => $crate::N4,
+            -3 => $crate::N3,
+            -2 => $crate::N2,
+            -1 => $crate::N1,
+            0 => $crate::Zero,
+            1 => $crate::P1,
+            2 => $crate::P2,
+            3 => $crate::P3,
+            4 => $crate::P4,
+            5 => $crate::P5,
+            6 => $crate::P6,
+            7 => $crate::P7,
+            8 => $crate::P8,
+            9 => $crate::P9,
+            10 => $crate::Succ<$crate::P9>,
+            11 => $crate::Succ<$crate::Succ<$crate::P9>>,
+            12 => $crate::Succ<$crate::Succ<$crate::Succ<$crate::P9>>>,
+            13 => $crate::Succ<$crate::Succ<$crate::Succ<$crate::Succ<$crate::P9>>>>,
+            14 => $crate::Succ<$crate::Succ<$crate::Succ<$crate::Succ<$crate::Succ<$crate::P9>>>>>,
+            15 => $crate::Succ<$crate::Succ<$crate::Succ<$crate::Succ<$crate::Succ<$crate::Succ<$crate::P9>>>>>>,
+            16 => $crate::Succ<$crate::Succ<$crate::Succ<$crate::Succ<$crate::Succ<$crate::Succ<$crate::Succ<$crate::P9>>>>>>>
+        )
+    };
+    (@arms $n:expr, $t:ident, $body:expr; $($v:pat => $ty:ty),+) => {
+        match $n {
+            $($v => { type $t = $ty; Some($body) })+
+            _ => None
+        }
+    };
+}
+
+macro_rules! dyn_num {
+    ($($variant:ident($ty:ty) = $v:expr),+) => {
+        /// A number type in the range `-16..=16` that is only known at runtime, e.g. from
+        /// parsing a configuration. Each variant holds an instance of the number type, so a
+        /// `match` can hand it to generic code (see `dispatch_tynum`).
+        #[derive(Copy,Clone,PartialEq,Eq,Debug,Hash)]
+        pub enum DynNum {
+            $($variant($ty)),+
+        }
+
+        impl DynNum {
+            /// Returns the value of the number.
+            pub fn value(&self) -> i64 {
+                match *self {
+                    $(DynNum::$variant(_) => $v),+
+                }
+            }
+        }
+
+        impl TryFrom<i64> for DynNum {
+            type Error = OutOfRange;
+
+            /// Returns the variant for `n`, or `OutOfRange` if it is outside of `-16..=16`.
+            fn try_from(n: i64) -> Result<DynNum, OutOfRange> {
+                match n {
+                    $($v => Ok(DynNum::$variant(<$ty>::new())),)+
+                    _ => Err(OutOfRange(n))
+                }
+            }
+        }
+    }
+}
+
+dyn_num!(
+    N16(Pred<Pred<Pred<Pred<Pred<Pred<Pred<N9>>>>>>>) = -16,
+    N15(Pred<Pred<Pred<Pred<Pred<Pred<N9>>>>>>) = -15,
+    N14(Pred<Pred<Pred<Pred<Pred<N9>>>>>) = -14,
+    N13(Pred<Pred<Pred<Pred<N9>>>>) = -13,
+    N12(Pred<Pred<Pred<N9>>>) = -12,
+    N11(Pred<Pred<N9>>) = -11,
+    N10(Pred<N9>) = -10,
+    N9(N9) = -9,
+    N8(N8) = -8,
+    N7(N7) = -7,
+    N6(N6) = -6,
+    N5(N5) = -5,
+    N4(N4) = -4,
+    N3(N3) = -3,
+    N2(N2) = -2,
+    N1(N1) = -1,
+    Zero(Zero) = 0,
+    P1(P1) = 1,
+    P2(P2) = 2,
+    P3(P3) = 3,
+    P4(P4) = 4,
+    P5(P5) = 5,
+    P6(P6) = 6,
+    P7(P7) = 7,
+    P8(P8) = 8,
+    P9(P9) = 9,
+    P10(Succ<P9>) = 10,
+    P11(Succ<Succ<P9>>) = 11,
+    P12(Succ<Succ<Succ<P9>>>) = 12,
+    P13(Succ<Succ<Succ<Succ<P9>>>>) = 13,
+    P14(Succ<Succ<Succ<Succ<Succ<P9>>>>>) = 14,
+    P15(Succ<Succ<Succ<Succ<Succ<Succ<P9>>>>>>) = 15,
+    P16(Succ<Succ<Succ<Succ<Succ<Succ<Succ<P9>>>>>>>) = 16
+);
+
+/// Error for a value that has no corresponding `DynNum`.
+#[derive(Copy,Clone,PartialEq,Eq,Debug)]
+pub struct OutOfRange(pub i64);
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is out of the range -16..=16 of DynNum", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRange {}
+
+/// Calls `f` with the `DynNum` for the runtime value `n`, returning `None` if `n` is outside
+/// of `-16..=16`. Unlike `with_tynum!`, this is a plain function, and `f` dispatches on the
+/// variant itself.
+///
+/// ```
+/// use tylar::{dispatch_tynum, DynNum, NumType};
+///
+/// let doubled = dispatch_tynum(3, |n| match n {
+///     DynNum::P3(p) => Into::<i32>::into(p) * 2,
+///     other => other.value() as i32,
+/// });
+/// assert_eq!(doubled, Some(6));
+/// assert_eq!(dispatch_tynum(-17, |n| n.value()), None);
+/// ```
+pub fn dispatch_tynum<R, F: FnOnce(DynNum) -> R>(n: i64, f: F) -> Option<R> {
+    DynNum::try_from(n).ok().map(f)
+}
+
+/// Generic code that is invoked for a number type chosen at runtime, see `reify`.
+pub trait NumVisitor {
+    /// Result of the visit, which must be the same for every number type.
+    type Out;
+    /// Invoked with the number type `N` matching the runtime value.
+    fn visit<N: NumType>(self) -> Self::Out;
+}
+
+/// Calls `v.visit::<N>()` with the number type `N` in the range `Lo..=Hi` whose value is `n`,
+/// or returns `None` if `n` is outside of the range. The visitor is monomorphized for every
+/// number type in the range, so the range should be small. See also `with_num!`.
+///
+/// ```
+/// use tylar::{reify, NumVisitor, NumType, Zero, P5};
+///
+/// struct Scale(i64);
+///
+/// impl NumVisitor for Scale {
+///     type Out = i64;
+///     fn visit<N: NumType>(self) -> i64 { self.0 * N::VALUE }
+/// }
+///
+/// assert_eq!(reify::<Zero, P5, _>(3, Scale(10)), Some(30));
+/// assert_eq!(reify::<Zero, P5, _>(6, Scale(10)), None);
+/// ```
+pub fn reify<Lo: NumType, Hi, V: NumVisitor>(n: i64, v: V) -> Option<V::Out>
+    where Hi: Sub<Lo>, <Hi as Sub<Lo>>::Out: ReifyFrom<Lo> {
+    <<Hi as Sub<Lo>>::Out as ReifyFrom<Lo>>::reify(n, v)
+}
+
+/// Compares the runtime value with `Lo` and the `Self` numbers after it, used to implement `reify`.
+#[doc(hidden)]
+pub trait ReifyFrom<Lo>: PosType {
+    fn reify<V: NumVisitor>(n: i64, v: V) -> Option<V::Out>;
+}
+impl<Lo: NumType> ReifyFrom<Lo> for Zero {
+    #[inline]
+    fn reify<V: NumVisitor>(n: i64, v: V) -> Option<V::Out> {
+        if n == Lo::VALUE { Some(v.visit::<Lo>()) } else { None }
+    }
+}
+impl<Lo: Incr, C: ReifyFrom<Lo::Out>> ReifyFrom<Lo> for Succ<C> {
+    #[inline]
+    fn reify<V: NumVisitor>(n: i64, v: V) -> Option<V::Out> {
+        if n == Lo::VALUE { Some(v.visit::<Lo>()) } else { C::reify(n, v) }
+    }
+}
+
+/// Invokes a block for the number type in a range matching a runtime value, and evaluates
+/// to `Some` with the value of the block, or to `None` if the value is out of range.
+///
+/// The bounds of the range must be integer literals in `-120..=120` (see
+/// `MAX_PRACTICAL_DEPTH`), and the result type of the block must be given. The block is
+/// monomorphized for each number type in the range, but it is type-checked only once with
+/// the bound `N: NumType`, and it can't refer to local variables (use `reify` with a
+/// custom `NumVisitor` for that).
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::NumType;
+///
+/// fn main() {
+///     let n = 20;
+///     assert_eq!(with_num!(n, 0..=32, |N| -> i64 { N::VALUE * 2 }), Some(40));
+///     assert_eq!(with_num!(-3, -5..=5, |N| -> i32 { N::new().into() }), Some(-3));
+///     assert_eq!(with_num!(33, 0..=32, |N| -> i64 { N::VALUE }), None);
+/// }
+/// ```
+#[macro_export]
+macro_rules! with_num {
+    ($n:expr, - $lo:tt $($rest:tt)*) => {
+        $crate::with_num!(@hi $n, ($crate::with_num!(@ty - $lo)) $($rest)*)
+    };
+    ($n:expr, $lo:tt $($rest:tt)*) => {
+        $crate::with_num!(@hi $n, ($crate::with_num!(@ty $lo)) $($rest)*)
+    };
+    (@hi $n:expr, $lo:tt ..= - $hi:tt, $($rest:tt)*) => {
+        $crate::with_num!(@call $n, $lo ($crate::with_num!(@ty - $hi)), $($rest)*)
+    };
+    (@hi $n:expr, $lo:tt ..= $hi:tt, $($rest:tt)*) => {
+        $crate::with_num!(@call $n, $lo ($crate::with_num!(@ty $hi)), $($rest)*)
+    };
+    (@call $n:expr, ($lo:ty) ($hi:ty), |$t:ident| -> $out:ty $body:block) => {{
+        struct Visitor;
+        impl $crate::NumVisitor for Visitor {
+            type Out = $out;
+            fn visit<$t: $crate::NumType>(self) -> $out $body
+        }
+        $crate::reify::<$lo, $hi, _>($n, Visitor)
+    }};
+    (@ty - $v:tt) => { <<$crate::Const<$v> as $crate::ToPeano>::Peano as $crate::Neg>::Out };
+    (@ty $v:tt) => { <$crate::Const<$v> as $crate::ToPeano>::Peano };
+}
+
+/// Runs a block once for every number type in a range, in ascending order, e.g. to test
+/// generic code or type-level operations for many numbers. This needs the `test-support`
+/// feature (which implies `macros`).
+///
+/// The bounds of the inclusive range must be integer literals with a magnitude of at most
+/// `MAX_PRACTICAL_DEPTH`. In the block, the given name is an alias for the concrete number
+/// type (see `n!`), so any operation on it can be used, and local variables can be used too:
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{NumType, Neg};
+///
+/// fn main() {
+///     let mut count = 0;
+///     for_each_tynum!(-8..=8, |T| {
+///         assert_eq!(<T as Neg>::Out::VALUE, -T::VALUE);
+///         count += 1;
+///     });
+///     assert_eq!(count, 17);
+/// }
+/// ```
+#[cfg(feature = "test-support")]
+#[macro_export]
+macro_rules! for_each_tynum {
+    ($lo:literal ..= $hi:literal, |$t:ident| $body:block) => {
+        $crate::for_range!(__tylar_value in $lo..=$hi {
+            {
+                #[allow(dead_code)]
+                type $t = $crate::n!(__tylar_value);
+                $body
+            }
+        });
+    };
+}
+
+/// The number type for an integer literal, e.g. `tnum!(7)` for `P7` and `tnum!(-12)` for
+/// the negative number with 12 nested `Pred`s. This can be used in type position.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{NumType, P7};
+///
+/// fn main() {
+///     type_eq!(tnum!(7), P7);
+///     assert_eq!(<tnum!(-12)>::VALUE, -12);
+///     assert_eq!(<tnum!(120)>::VALUE, 120);
+/// }
+/// ```
+///
+/// The magnitude of the literal must be at most `MAX_PRACTICAL_DEPTH`. Larger literals are
+/// rejected with an error that says so ("`Const<121>` exceeds `MAX_PRACTICAL_DEPTH`"),
+/// instead of running into the recursion limit:
+///
+/// ```compile_fail
+/// #[macro_use] extern crate tylar;
+/// use tylar::NumType;
+///
+/// fn main() {
+///     let _ = <tnum!(121)>::VALUE;
+/// }
+/// ```
+#[macro_export]
+macro_rules! tnum {
+    (- $v:literal) => {
+        <<$crate::Const<$v> as $crate::ToPeano>::Peano as $crate::Neg>::Out
+    };
+    ($v:literal) => {
+        <$crate::Const<$v> as $crate::ToPeano>::Peano
+    };
+}
+
+/// Shorthand for `tnum!`, e.g. `num!(7)` for `P7` and `num!(-12)` for `N12`.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{NumType, PosType};
+///
+/// struct Matrix<R: PosType, C: PosType>(R, C);
+///
+/// fn cells<R: PosType, C: PosType>(_: &Matrix<R, C>) -> u64 { R::UVALUE * C::UVALUE }
+///
+/// fn main() {
+///     let m: Matrix<num!(3), num!(64)> = Matrix(NumType::new(), NumType::new());
+///     assert_eq!(cells(&m), 192);
+/// }
+/// ```
+#[macro_export]
+macro_rules! num {
+    ($($t:tt)*) => { $crate::tnum!($($t)*) };
+}
+
+/// The number type for an infix expression over number types, with the operators
+/// `+ - * / %` and parentheses, e.g. `calc!((N * N4 + P2) / P2)` for
+/// `<<<N as Mul<N4>>::Out as Add<P2>>::Out as Div<P2>>::Out`. Operators have the usual
+/// precedence and are left-associative. This can be used in type position and in
+/// where-clauses:
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{NumType, Add, Mul, P1, P3, N2};
+///
+/// // (N + 1) * 3, for any number N
+/// fn calculate<N: NumType>() -> i64 where N: Add<P1>, calc!(N + P1): Mul<P3> {
+///     <calc!((N + P1) * P3)>::VALUE
+/// }
+///
+/// fn main() {
+///     type_eq!(calc!(P3 - N2 * P3), tylar::P9);
+///     assert_eq!(calculate::<N2>(), -3);
+/// }
+/// ```
+///
+/// Operands can be any types (but no other expressions), so numbers can be written with
+/// `tnum!` as well, e.g. `calc!(tnum!(12) % P5)`.
+#[macro_export]
+macro_rules! calc {
+    // sums and differences of terms, with the sum so far in `[$acc]`, the pending operation
+    // `$op` and the tokens of the current term in `[$cur]`
+    (@sum [$($acc:tt)*] $op:ident [$($cur:tt)+] + $($rest:tt)*) => {
+        $crate::calc!(@sum [$crate::calc!(@apply $op [$($acc)*] [$crate::calc!(@prod [] Start [] $($cur)+)])] Add [] $($rest)*)
+    };
+    (@sum [$($acc:tt)*] $op:ident [$($cur:tt)+] - $($rest:tt)*) => {
+        $crate::calc!(@sum [$crate::calc!(@apply $op [$($acc)*] [$crate::calc!(@prod [] Start [] $($cur)+)])] Sub [] $($rest)*)
+    };
+    (@sum [$($acc:tt)*] $op:ident [$($cur:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::calc!(@sum [$($acc)*] $op [$($cur)* $t] $($rest)*)
+    };
+    (@sum [$($acc:tt)*] $op:ident [$($cur:tt)+]) => {
+        $crate::calc!(@apply $op [$($acc)*] [$crate::calc!(@prod [] Start [] $($cur)+)])
+    };
+    // products, quotients and remainders of operands, in the same way
+    (@prod [$($acc:tt)*] $op:ident [$($cur:tt)+] * $($rest:tt)*) => {
+        $crate::calc!(@prod [$crate::calc!(@apply $op [$($acc)*] [$crate::calc!(@operand $($cur)+)])] Mul [] $($rest)*)
+    };
+    (@prod [$($acc:tt)*] $op:ident [$($cur:tt)+] / $($rest:tt)*) => {
+        $crate::calc!(@prod [$crate::calc!(@apply $op [$($acc)*] [$crate::calc!(@operand $($cur)+)])] Div [] $($rest)*)
+    };
+    (@prod [$($acc:tt)*] $op:ident [$($cur:tt)+] % $($rest:tt)*) => {
+        $crate::calc!(@prod [$crate::calc!(@apply $op [$($acc)*] [$crate::calc!(@operand $($cur)+)])] Rem [] $($rest)*)
+    };
+    (@prod [$($acc:tt)*] $op:ident [$($cur:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::calc!(@prod [$($acc)*] $op [$($cur)* $t] $($rest)*)
+    };
+    (@prod [$($acc:tt)*] $op:ident [$($cur:tt)+]) => {
+        $crate::calc!(@apply $op [$($acc)*] [$crate::calc!(@operand $($cur)+)])
+    };
+    (@operand ($($e:tt)+)) => { $crate::calc!($($e)+) };
+    (@operand $t:ty) => { $t };
+    (@apply Start [] [$b:ty]) => { $b };
+    (@apply $op:ident [$a:ty] [$b:ty]) => { <$a as $crate::$op<$b>>::Out };
+    ($($e:tt)+) => { $crate::calc!(@sum [] Start [] $($e)+) };
+}
+
+/// Checks an operation on number types against the corresponding arithmetic on `i64`, for
+/// all combinations of the given operands, e.g. in tests. Given a closure with one or two
+/// parameters, the operation is `<A as Op>::Out` or `<A as Op<B>>::Out`, and the number type
+/// it produces must convert to the value of the closure (called with the values of the
+/// operands). Without explicit operand lists, all numbers from –10 to 10 are used.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+///
+/// fn main() {
+///     check_op!(Add, |a, b| a + b);
+///     check_op!(Neg, |a| -a);
+///     // `Div` is not implemented for a divisor of `Zero`, so leave it out
+///     check_op!(Div, |a, b| a / b, [tylar::N5, tylar::P7], [tylar::N2, tylar::P3]);
+/// }
+/// ```
+///
+/// Since every combination of operands is a separate instance of the operation, the
+/// operand lists must only contain numbers for which the operation is implemented.
+#[macro_export]
+macro_rules! check_op {
+    ($op:ident, |$a:ident, $b:ident| $e:expr) => {
+        $crate::check_op!(@default [@default [$op, |$a, $b| $e,] ,])
+    };
+    ($op:ident, |$a:ident| $e:expr) => {
+        $crate::check_op!(@default [$op, |$a| $e,])
+    };
+    ($op:ident, |$a:ident, $b:ident| $e:expr, [$($ta:ty),* $(,)?], $tbs:tt) => {{
+        let expected = |$a: i64, $b: i64| -> i64 { $e };
+        $( $crate::check_op!(@row $op, expected, $ta, $tbs); )*
+    }};
+    ($op:ident, |$a:ident| $e:expr, [$($ta:ty),* $(,)?]) => {{
+        let expected = |$a: i64| -> i64 { $e };
+        $({
+            let actual: i64 = <<$ta as $crate::$op>::Out as $crate::NumType>::new().into();
+            let a = <$ta as $crate::NumType>::VALUE;
+            assert_eq!(actual, expected(a), "{}({})", stringify!($op), a);
+        })*
+    }};
+    (@row $op:ident, $expected:ident, $ta:ty, [$($tb:ty),* $(,)?]) => {$({
+        let actual: i64 = <<$ta as $crate::$op<$tb>>::Out as $crate::NumType>::new().into();
+        let (a, b) = (<$ta as $crate::NumType>::VALUE, <$tb as $crate::NumType>::VALUE);
+        assert_eq!(actual, $expected(a, b), "{}({}, {})", stringify!($op), a, b);
+    })*};
+    // appends the default operands to the arguments
+    (@default [$($args:tt)*] $($more:tt)*) => {
+        $crate::check_op!($($args)* [$crate::N10, $crate::N9, $crate::N8, $crate::N7, $crate::N6, $crate::N5, $crate::N4,
+         $crate::N3, $crate::N2, $crate::N1, $crate::Zero, $crate::P1, $crate::P2, $crate::P3,
+         $crate::P4, $crate::P5, $crate::P6, $crate::P7, $crate::P8, $crate::P9, $crate::P10] $($more)*)
+    };
+}
+
+/// Defines a wrapper around a collection that tracks the number of elements in its type,
+/// like `stack::TStack` does for `Vec`. The collection must be a `Sequence`, i.e. `Vec`,
+/// `VecDeque` or `LinkedList`, which keep every element that is added.
+///
+/// ```
+/// #[macro_use] extern crate tylar;
+/// use tylar::{PosType, P2, P3};
+///
+/// indexed_wrapper! {
+///     /// A buffer of bytes with a known length.
+///     pub struct Bytes(Vec<u8>);
+/// }
+///
+/// // domain methods can be added with the collection in the `inner` field
+/// impl<N: PosType> Bytes<N> {
+///     fn checksum(&self) -> u8 { self.inner.iter().fold(0, |a, &b| a ^ b) }
+/// }
+///
+/// fn main() {
+///     let a: Bytes<P2> = Bytes::new().push(1).push(2);
+///     let b: Bytes<P3> = a.append(Bytes::new().push(4));
+///     assert_eq!(b.len(), 3);
+///     assert_eq!(b.checksum(), 7);
+///     assert_eq!(b.into_inner(), vec![1, 2, 4]);
+/// }
+/// ```
+///
+/// The generated struct has the type parameter `N: PosType` for the number of elements and
+/// the following methods:
+///
+/// * `new()` and `Default::default()` create an empty wrapper (with `N` = `Zero`).
+/// * `push(item)` appends an element and increments `N`.
+/// * `append(other)` appends the elements of another wrapper of the same type and adds the
+///   numbers of elements.
+/// * `len()` and `is_empty()` return the number of elements, as given by `N`.
+/// * `inner()` and `into_inner()` return the collection.
+///
+/// It also implements `Debug` (if the collection does), which shows the collection and `N`.
+#[macro_export]
+macro_rules! indexed_wrapper {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident($inner:ty);) => {
+        $(#[$attr])*
+        $vis struct $name<N: $crate::PosType> where $inner: $crate::Sequence {
+            inner: $inner, // the number of elements is always `N`
+            p: $crate::macro_support::PhantomData<N>
+        }
+
+        #[allow(dead_code)]
+        impl $name<$crate::Zero> {
+            /// Creates a new, empty wrapper.
+            pub fn new() -> Self {
+                $name { inner: <$inner as $crate::macro_support::Default>::default(), p: $crate::macro_support::PhantomData }
+            }
+        }
+
+        impl $crate::macro_support::Default for $name<$crate::Zero> {
+            fn default() -> Self { Self::new() }
+        }
+
+        #[allow(dead_code)]
+        impl<N: $crate::PosType> $name<N> {
+            /// Appends an element.
+            pub fn push(self, item: <$inner as $crate::macro_support::IntoIterator>::Item) -> $name<$crate::Succ<N>> {
+                let mut inner = self.inner;
+                inner.extend($crate::macro_support::Some(item));
+                $name { inner, p: $crate::macro_support::PhantomData }
+            }
+
+            /// Appends the elements of another wrapper.
+            pub fn append<M: $crate::PosType>(self, other: $name<M>) -> $name<<N as $crate::Add<M>>::Out>
+                where N: $crate::Add<M>, <N as $crate::Add<M>>::Out: $crate::PosType
+            {
+                let mut inner = self.inner;
+                inner.extend(other.inner);
+                $name { inner, p: $crate::macro_support::PhantomData }
+            }
+
+            /// Returns the number of elements, i.e. the value of `N`.
+            #[inline(always)]
+            pub fn len(&self) -> usize { <N as $crate::PosType>::USIZE }
+
+            /// Returns whether there are no elements, i.e. whether `N` is `Zero`.
+            #[inline(always)]
+            pub fn is_empty(&self) -> bool { <N as $crate::PosType>::USIZE == 0 }
+
+            /// Returns a reference to the collection.
+            pub fn inner(&self) -> &$inner { &self.inner }
+
+            /// Returns the collection.
+            pub fn into_inner(self) -> $inner { self.inner }
+        }
+
+        impl<N: $crate::PosType> $crate::macro_support::fmt::Debug for $name<N> where $inner: $crate::macro_support::fmt::Debug {
+            fn fmt(&self, f: &mut $crate::macro_support::fmt::Formatter) -> $crate::macro_support::fmt::Result {
+                $crate::macro_support::fmt::Debug::fmt(&self.inner, f)?;
+                write!(f, " ({})", <N as $crate::PosType>::USIZE)
+            }
+        }
+    };
+}
+
+/// Collections that keep every element that is added with `Extend`, in order, which
+/// `indexed_wrapper!` requires so that the number of elements is always `N`. This is
+/// implemented for `Vec`, `VecDeque` and `LinkedList` (with the `alloc` feature), but
+/// not for sets or maps, which drop duplicates. This trait is sealed.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be wrapped by `indexed_wrapper!`",
+    label = "not a `Sequence`",
+    note = "only collections that keep every element, like `Vec` and `VecDeque`, can track their length in the type"
+)]
+pub trait Sequence: private::SequenceSealed + Default + IntoIterator + Extend<<Self as IntoIterator>::Item> {}
+
+#[cfg(feature = "alloc")]
+impl<T> private::SequenceSealed for alloc::vec::Vec<T> {}
+#[cfg(feature = "alloc")]
+impl<T> Sequence for alloc::vec::Vec<T> {}
+#[cfg(feature = "alloc")]
+impl<T> private::SequenceSealed for alloc::collections::VecDeque<T> {}
+#[cfg(feature = "alloc")]
+impl<T> Sequence for alloc::collections::VecDeque<T> {}
+#[cfg(feature = "alloc")]
+impl<T> private::SequenceSealed for alloc::collections::LinkedList<T> {}
+#[cfg(feature = "alloc")]
+impl<T> Sequence for alloc::collections::LinkedList<T> {}
+
+/// Re-exports of `core` for the expansion of `indexed_wrapper!` in crates that don't have
+/// `core` in scope.
+#[doc(hidden)]
+pub mod macro_support {
+    pub use core::default::Default;
+    pub use core::fmt;
+    pub use core::iter::{Extend, IntoIterator};
+    pub use core::marker::PhantomData;
+    pub use core::option::Option::Some;
+}
+
+#[test]
+#[allow(clippy::multiple_bound_locations, clippy::extra_unused_type_parameters)]
+fn num_macro() {
+    struct Grid<W: PosType, H: NegType> { w: W, h: H }
+    let g: Grid<num!(12), num!(-7)> = Grid { w: NumType::new(), h: NumType::new() };
+    assert_eq!(Into::<i32>::into(g.w), 12);
+    assert_eq!(Into::<i32>::into(g.h), -7);
+
+    fn sum<A: NumType, B: NumType>() -> i64 where A: Add<B, Out=num!(10)> { <num!(10)>::VALUE }
+    assert_eq!(sum::<num!(-54), num!(64)>(), 10);
+
+    type_eq!(num!(0), Zero);
+    type_eq!(num!(9), P9);
+    type_eq!(num!(-9), N9);
+    type_eq!(num!(64), Plus50<Plus10<P4>>);
+    assert_eq!(Into::<i64>::into(<num!(-64)>::new()), -64);
+    assert_eq!(<num!(120)>::VALUE, 120);
+}
+
+#[test]
+fn zero_sized() {
+    use std::mem::size_of;
+    assert_eq!(size_of::<Zero>(), 0);
+    assert_eq!(size_of::<P1>(), 0);
+    assert_eq!(size_of::<N1>(), 0);
+    assert_eq!(size_of::<Plus50<Zero>>(), 0);
+}
+
+#[test]
+fn into_number() {
+    assert_eq!(0, Zero::new().into());
+    assert_eq!(-3, N3::new().into());
+    assert_eq!(2, P2::new().into());
+    assert_eq!(2i8, P2::new().into());
+    assert_eq!(2u64, P2::new().into());
+    assert_eq!(2u8, P2::new().into());
+    
+    // 63 seems to be the maximal nesting depth acceptable to the compiler
+    type P63 = Plus10<Plus50<P3>>;
+    assert_eq!(63, P63::new().into());
+}
+
+#[test]
+fn consts() {
+    assert_eq!(16, P16::new().into());
+    assert_eq!(32u8, P32::new().into());
+    assert_eq!(63, P63::new().into());
+    assert_eq!(-42i8, N42::new().into());
+    assert_eq!(-64, N64::new().into());
+    type_eq!(P63, Plus10<Plus50<P3>>);
+    type_eq!(N10, Pred<N9>);
+    type_eq!(N42, <P42 as Neg>::Out);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn ring_buffer() {
+    use ring::RingBuf;
+
+    let mut buf = RingBuf::<_, P3>::new();
+    assert!(buf.is_empty());
+    assert_eq!(buf.capacity(), 3);
+    assert_eq!(buf.push(1), None);
+    assert_eq!(buf.push(2), None);
+    assert_eq!(buf.push(3), None);
+    assert!(buf.is_full());
+    assert_eq!(buf.push(4), Some(1));
+    assert_eq!(buf.push(5), Some(2));
+    assert_eq!(buf.len(), 3);
+    assert_eq!(buf.iter().cloned().collect::<Vec<_>>(), vec![3, 4, 5]);
+    assert_eq!(format!("{:?}", buf), "[3, 4, 5] (3/3)");
+    for i in 6..13 {
+        buf.push(i);
+    }
+    assert_eq!(buf.iter().cloned().collect::<Vec<_>>(), vec![10, 11, 12]);
+
+    let mut none = RingBuf::<_, Zero>::new();
+    assert_eq!(none.push('a'), Some('a'));
+    assert!(none.is_empty());
+}
+
+#[test]
+fn unrolled_repetition() {
+    fn indices<N: Unroll>() -> Vec<usize> {
+        let mut v = Vec::new();
+        repeat::<N, _>(|i| v.push(i));
+        v
+    }
+    assert_eq!(indices::<Zero>(), []);
+    assert_eq!(indices::<P1>(), [0]);
+    assert_eq!(indices::<P5>(), [0, 1, 2, 3, 4]);
+
+    let mut calls = 0;
+    repeat::<Plus10<P2>, _>(|_| calls += 1);
+    assert_eq!(calls, 12);
+
+    // with the length of an array given by a number type
+    fn dot<N: Unroll, const K: usize>(a: &[i32; K], b: &[i32; K]) -> i32 where Const<K>: ToPeano<Peano=N> {
+        let mut sum = 0;
+        repeat::<N, _>(|i| sum += a[i] * b[i]);
+        sum
+    }
+    assert_eq!(dot(&[1, 2, 3], &[4, 5, 6]), 32);
+    assert_eq!(dot(&[], &[]), 0);
+}
+
+#[test]
+fn tuple_folds() {
+    assert_eq!(sum_values::<(P1, P2, P3)>(), 6);
+    assert_eq!(product_values::<(P1, P2, P3)>(), 6);
+    assert_eq!(sum_values::<(N7,)>(), -7);
+    assert_eq!(product_values::<(N7,)>(), -7);
+    assert_eq!(sum_values::<(P4, N4)>(), 0);
+    assert_eq!(product_values::<(P4, N4)>(), -16);
+    assert_eq!(product_values::<(P9, P5, Zero, P3)>(), 0);
+    assert_eq!(sum_values::<(P1, P2, P3, P4, P5, P6, P7, P8)>(), 36);
+    assert_eq!(product_values::<(P1, P2, P3, P4, P5, P6, P7, P8)>(), 40320);
+    const LEN: usize = sum_values::<(P2, P3)>() as usize;
+    assert_eq!([0u8; LEN].len(), 5);
+}
+
+#[test]
+fn const_generics() {
+    type_eq!(<Const<0> as ToPeano>::Peano, Zero);
+    type_eq!(<Const<8> as ToPeano>::Peano, P8);
+    type_eq!(<Const<64> as ToPeano>::Peano, Plus50<Plus10<P4>>);
+    let buf = [0u8; <<Const<5> as ToPeano>::Peano as PosType>::USIZE];
+    assert_eq!(buf.len(), 5);
+    let buf = [1u8; P4::USIZE];
+    assert_eq!(buf.len(), 4);
+    let arr: [i32; 4] = make_array::<_, P4, _>();
+    assert_eq!(arr, [0; 4]);
+    assert_eq!(make_array::<char, Zero, 0>(), [] as [char; 0]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stack_arrays() {
+    use stack::TStack;
+
+    let stack: TStack<_, P3> = [1, 2, 3].into();
+    let stack = stack.push(4);
+    let array: [i32; 4] = stack.into();
+    assert_eq!(array, [1, 2, 3, 4]);
+    let empty: TStack<u8, Zero> = [].into();
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn type_stack() {
+    use stack::TStack;
+
+    let s = TStack::new().push(1).push(2).push(3);
+    assert_eq!(s.len(), 3);
+    assert_eq!(*s.peek(), 3);
+    let (s, c) = s.pop();
+    let (s, b) = s.pop();
+    let s = s.push(4);
+    let (s, d) = s.pop();
+    let (s, a): (TStack<i32, Zero>, _) = s.pop();
+    assert_eq!((a, b, c, d), (1, 2, 3, 4));
+    assert!(s.is_empty());
+    assert_eq!(format!("{:?}", s.push(5)), "[5] (1)");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stack_iteration() {
+    use stack::TStack;
+
+    let s = TStack::new().push('a').push('b').push('c');
+    assert_eq!(s.iter().collect::<String>(), "abc");
+    let mut n = 0;
+    for _ in &s { n += 1; }
+    assert_eq!(n, s.len());
+    assert_eq!(s.into_iter().rev().collect::<Vec<_>>(), vec!['c', 'b', 'a']);
+    assert_eq!(TStack::<u8, Zero>::new().into_iter().next(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stack_map() {
+    use stack::TStack;
+
+    let s: TStack<i32, P3> = [1, -2, 30].into();
+    let s: TStack<String, P3> = s.map(|v| v.to_string());
+    assert_eq!(s.len(), 3);
+    let (s, top) = s.pop();
+    assert_eq!(top, "30");
+    let mut n = 0;
+    let s = s.map(|v| { n += 1; v.len() });
+    assert_eq!(n, 2);
+    let array: [usize; 2] = s.into();
+    assert_eq!(array, [1, 2]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stack_replicate() {
+    use stack::TStack;
+
+    let s: TStack<i32, P4> = TStack::replicate(0);
+    let array: [i32; 4] = s.into();
+    assert_eq!(array, [0; 4]);
+    let s = TStack::<String, P2>::replicate(String::from("ab")).push(String::from("c"));
+    assert_eq!(s.into_iter().collect::<String>(), "ababc");
+    assert!(TStack::<u8, Zero>::replicate(1).is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stack_split() {
+    use stack::TStack;
+
+    let s: TStack<_, P5> = ['a', 'b', 'c', 'd', 'e'].into();
+    let (bottom, top): (TStack<_, P2>, TStack<_, P3>) = s.split_at::<P2>();
+    assert_eq!(bottom.into_iter().collect::<String>(), "ab");
+    assert_eq!(top.iter().collect::<String>(), "cde");
+
+    let (all, none) = top.split_at::<P3>();
+    assert_eq!((all.len(), none.len()), (3, 0));
+    let (none, all): (TStack<_, Zero>, TStack<_, P3>) = all.split_at::<Zero>();
+    assert!(none.is_empty());
+    assert_eq!(*all.peek(), 'e');
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stack_zip() {
+    use stack::TStack;
+
+    let numbers: TStack<_, P3> = [1, 2, 3].into();
+    let letters = TStack::new().push('a').push('b').push('c');
+    let pairs: TStack<(i32, char), P3> = numbers.zip(letters);
+    assert_eq!(pairs.iter().cloned().collect::<Vec<_>>(), vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+    let empty: TStack<(u8, u8), Zero> = TStack::new().zip(TStack::new());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn std_traits() {
+    use std::collections::HashMap;
+
+    assert_eq!(format!("{}", P3::new()), "3");
+    assert_eq!(format!("{}", N3::new()), "-3");
+    assert_eq!(format!("{}", Zero::new()), "0");
+    assert_eq!(format!("{:>4}", Plus50::<P2>::new()), "  52");
+    assert_eq!(format!("{:?}", P3::new()), "P3");
+    assert_eq!(format!("{:?}", N3::new()), "N3");
+    assert_eq!(format!("{:?}", Zero::new()), "Zero");
+    assert_eq!(format!("{:?}", Some(Plus10::<Zero>::new())), "Some(P10)");
+
+    let x: P4 = Default::default();
+    assert_eq!(x, P4::new());
+
+    let mut map = HashMap::new();
+    map.insert(P2::new(), "two");
+    assert_eq!(map.get(&P2::new()), Some(&"two"));
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn default_instances() {
+    #[derive(Default, Debug, PartialEq)]
+    struct Tagged<V> {
+        version: V,
+        payload: u32,
+    }
+
+    assert_eq!(5, Into::<i64>::into(P5::default()));
+    assert_eq!(-2, Into::<i64>::into(N2::default()));
+    assert_eq!(0, Into::<i64>::into(<Zero as Default>::default()));
+    let t: Tagged<P3> = Default::default();
+    assert_eq!(t, Tagged { version: P3::new(), payload: 0 });
+}
+
+#[test]
+fn increment_instances() {
+    let x: P5 = P3::new().incr().incr();
+    assert_eq!(5, x.into());
+    let y: N2 = P1::new().decr().decr().decr();
+    assert_eq!(-2, y.into());
+    let z: Zero = N2::new().incr().decr().incr().incr();
+    assert_eq!(0, z.into());
+    let w: P1 = Zero::new().magnitude().incr();
+    assert_eq!(1, w.into());
+}
+
+#[test]
+fn negate_instances() {
+    let x: N5 = P5::new().negate();
+    assert_eq!(x, -5);
+    let y: P5 = P5::new().negate().negate();
+    assert_eq!(y, P5::new());
+    let z: Zero = Zero::new().negate();
+    assert_eq!(z, 0);
+    let w: P3 = N2::new().negate().incr();
+    assert_eq!(w, 3);
+
+    // `neg()` is left to the operator trait, without ambiguity when it is in scope
+    use core::ops::Neg as _;
+    let v: N3 = P3::new().neg();
+    assert_eq!(v, P3::new().negate());
+}
+
+#[test]
+fn from_number() {
+    assert_eq!(0i64, i64::from(Zero::new()));
+    assert_eq!(-3i64, i64::from(N3::new()));
+    assert_eq!(4i64, i64::from(P4::new()));
+    assert_eq!(4u8, u8::from(P4::new()));
+    assert_eq!(0usize, usize::from(Zero::new()));
+
+    let z: i16 = Zero::new().into();
+    let n: i16 = N3::new().into();
+    let p: u16 = P4::new().into();
+    assert_eq!((0, -3, 4), (z, n, p));
+
+    fn generic<T: From<P5> + From<N5>>() -> (T, T) { (T::from(P5::new()), T::from(N5::new())) }
+    assert_eq!((5i32, -5i32), generic::<i32>());
+}
+
+#[test]
+fn narrow_conversions() {
+    type P127 = Plus100<Plus10<Plus10<Plus5<P2>>>>;
+    type P200 = Plus100<Plus100<Zero>>;
+    type N128 = <Succ<P127> as Neg>::Out;
+
+    assert_eq!(127i8, P127::new().into());
+    assert_eq!(-128i8, N128::new().into());
+    assert_eq!(200u8, P200::new().into());
+    assert_eq!(-128i16, N128::new().into());
+    assert_eq!(200u16, P200::new().into());
+    assert_eq!(0u8, Zero::new().into());
+
+    fn fits<T: NumType + Into<u8>>() -> u8 { T::new().into() }
+    assert_eq!(9, fits::<P9>());
+}
+
+#[test]
+fn large_conversions() {
+    type P100 = Plus100<Zero>;
+    type N100 = Minus100<Zero>;
+    type P250 = Plus100<Plus100<Plus50<Zero>>>;
+    type N250 = <P250 as Neg>::Out;
+
+    // the value is cast once, so no intermediate step can overflow in debug builds
+    assert_eq!(100i8, P100::new().into());
+    assert_eq!(-100i8, N100::new().into());
+    assert_eq!(100u8, P100::new().into());
+    assert_eq!(250u8, P250::new().into());
+    assert_eq!(-250i16, N250::new().into());
+    assert_eq!(-250i32, N250::new().into());
+    assert_eq!(-250i128, N250::new().into());
+    assert_eq!(-250isize, N250::new().into());
+    assert_eq!(-250.0f32, N250::new().into());
+    assert_eq!(250u32, P250::new().into());
+    assert_eq!(250u128, P250::new().into());
+    assert_eq!(250usize, P250::new().into());
+}
+
+#[test]
+fn non_zero_conversions() {
+    assert_eq!(NonZeroU64::from(P5::new()).get(), 5);
+    assert_eq!(NonZeroI64::from(P5::new()).get(), 5);
+    assert_eq!(NonZeroI64::from(N5::new()).get(), -5);
+    assert_eq!(NonZeroI64::from(N1::new()).get(), -1);
+    fn stride<S: NonZero + Into<NonZeroI64>>(s: S) -> NonZeroI64 { s.into() }
+    assert_eq!(stride(Plus10::<P1>::new()).get(), 11);
+}
+
+#[test]
+fn compare_with_primitives() {
+    assert_eq!(P5::new(), 5);
+    assert_eq!(N2::new(), -2i8);
+    assert_eq!(Zero::new(), 0usize);
+    assert!(P5::new() == 5u8 && P5::new() != 4u64 && P5::new() != -5i16);
+    assert!(N3::new() != 3u32 && N3::new() == -3isize);
+    assert!(N2::new() < 0 && N2::new() < 0u8 && N2::new() > -3i64);
+    assert!(P9::new() > 8i16 && P9::new() >= 9u16 && P9::new() <= 9usize);
+    assert!(Zero::new() <= 0 && Zero::new() >= 0u64 && Zero::new() < 1i32);
+    assert!(Plus100::<Plus100<Zero>>::new() > i8::MAX && Plus100::<Plus100<Zero>>::new() < u8::MAX);
+}
+
+#[test]
+fn runtime_checks() {
+    assert!(P5::matches(5));
+    assert!(!P5::matches(4));
+    assert!(!P5::matches(-5));
+    assert!(Zero::matches(0));
+    assert_eq!(P5::check(5), Some(P5::new()));
+    assert_eq!(P5::check(6), None);
+
+    assert!(N3::matches(-3));
+    assert!(!N3::matches(3));
+    assert_eq!(N3::check(-3), Some(N3::new()));
+    assert_eq!(N3::check(3), None);
+    assert_eq!(N1::check(i64::MIN), None);
+
+    assert!(P4::matches_usize(4));
+    assert!(!P4::matches_usize(usize::MAX));
+    assert_eq!(P4::check_usize(4), Some(P4::new()));
+    assert_eq!(Zero::check_usize(1), None);
+    assert_eq!(P7::check_u64(7), Some(P7::new()));
+    assert_eq!(P7::check_u64(u64::MAX), None);
+
+    fn guard<N: PosType>(buf: &[u8]) -> Option<N> { N::check_usize(buf.len()) }
+    assert_eq!(guard::<P3>(&[1, 2, 3]), Some(P3::new()));
+    assert_eq!(guard::<P3>(&[1, 2]), None);
+}
+
+#[test]
+fn saturating_conversions() {
+    type P200 = Plus100<Plus100<Zero>>;
+    type N200 = <P200 as Neg>::Out;
+
+    assert_eq!(P200::new().saturating_into_i8(), 127);
+    assert_eq!(N200::new().saturating_into_i8(), -128);
+    assert_eq!(P200::new().saturating_into_u8(), 200);
+    assert_eq!(N200::new().saturating_into_u8(), 0);
+    assert_eq!(N200::new().saturating_into_i16(), -200);
+    assert_eq!(N3::new().saturating_into_u16(), 0);
+    assert_eq!(N3::new().saturating_into_u32(), 0);
+    assert_eq!(N3::new().saturating_into_i8(), -3);
+    assert_eq!(P9::new().saturating_into_u8(), 9);
+    assert_eq!(Zero::new().saturating_into_i8(), 0);
+}
+
+#[test]
+fn wrapping_conversions() {
+    type P127 = Plus100<Plus10<Plus10<P7>>>;
+    type P130 = Plus100<Plus10<Plus10<Plus10<Zero>>>>;
+    assert_eq!(P130::new().wrapping_into_i8(), -126);
+    assert_eq!(P127::new().wrapping_into_i8(), 127);
+    assert_eq!(P130::new().wrapping_into_u8(), 130);
+    assert_eq!(N1::new().wrapping_into_u8(), 255);
+    assert_eq!(N1::new().wrapping_into_u16(), u16::MAX);
+    assert_eq!(N1::new().wrapping_into_u32(), u32::MAX);
+    assert_eq!(N64::new().wrapping_into_i16(), -64);
+    assert_eq!(Zero::new().wrapping_into_u8(), 0);
+}
+
+#[test]
+fn into_i128() {
+    assert_eq!(-3i128, N3::new().into());
+    assert_eq!(2u128, P2::new().into());
+    assert_eq!(0, as_i128::<Zero>());
+    assert_eq!(-3, as_i128::<N3>());
+    assert_eq!(63, as_i128::<P63>());
+}
+
+#[test]
+fn into_128_bit() {
+    fn both<T: PosType>() -> (i128, u128) { (T::new().into(), T::new().into()) }
+
+    assert_eq!((0, 0), both::<Zero>());
+    assert_eq!((9, 9), both::<P9>());
+    assert_eq!((57, 57), both::<Plus50<P7>>());
+    assert_eq!(-9i128, N9::new().into());
+    assert_eq!(-9, as_i128::<N9>());
+}
+
+#[test]
+fn into_float() {
+    fn scale<E: NumType>(x: f64) -> f64 { x * 2f64.powf(E::to_f64()) }
+
+    assert_eq!(0.0f64, Zero::new().into());
+    assert_eq!(5.0f64, P5::new().into());
+    assert_eq!(-7.0f32, N7::new().into());
+    assert_eq!(52.0f32, Plus50::<P2>::new().into());
+    assert_eq!(-7.0, N7::to_f64());
+    assert_eq!(24.0, scale::<P3>(3.0));
+    assert_eq!(0.75, scale::<N2>(3.0));
+}
+
+#[test]
+fn constant_value() {
+    const FIFTY_THREE: i64 = <Plus50<P3> as NumType>::VALUE;
+    const LEN: usize = Plus50::<Zero>::USIZE;
+    const ZERO: Zero = Zero::new();
+    const THREE: P3 = P3::new();
+    
+    assert_eq!(53, FIFTY_THREE);
+    assert_eq!(50, LEN);
+    assert_eq!(0, ZERO.into());
+    assert_eq!(3, THREE.into());
+    assert_eq!(-7, N7::VALUE);
+    assert_eq!(7, P7::UVALUE);
+    assert_eq!(0, Zero::USIZE);
+    
+    let array = [0u8; P8::USIZE];
+    assert_eq!(8, array.len());
+    
+    fn value<N: NumType>() -> i64 { N::VALUE }
+    assert_eq!(-3, value::<N3>());
+}
+
+#[test]
+fn const_value_of() {
+    const LEN: usize = value_of::<P8>() as usize;
+    let buf = [0u8; value_of::<P8>() as usize];
+    assert_eq!(buf.len(), 8);
+    assert_eq!(LEN, 8);
+    assert_eq!(value_of::<N9>(), -9);
+    assert_eq!(value_of::<Plus50<P3>>(), 53);
+}
+
+#[test]
+fn max_practical_depth() {
+    assert_eq!(PMax::VALUE, MAX_PRACTICAL_DEPTH as i64);
+    assert_eq!(PMax::USIZE, MAX_PRACTICAL_DEPTH);
+    assert_eq!(<PMax as Depth>::DEPTH, MAX_PRACTICAL_DEPTH);
+}
+
+#[test]
+fn building_blocks() {
+    assert_eq!(63, Plus10::<Plus50<P3>>::new().into());
+    assert_eq!(42i8, Plus5::<Plus10<Plus10<Plus10<Plus5<P2>>>>>::new().into());
+    assert_eq!(-30, Minus10::<Minus10<Minus10<Zero>>>::new().into());
+    assert_eq!(-112i64, Minus100::<Minus10<N2>>::new().into());
+    assert_eq!(-57i16, Minus50::<Minus5<N2>>::new().into());
+    type_eq!(Minus100<Minus10<N2>>, <Plus100<Plus10<P2>> as Neg>::Out);
+    type_eq!(Minus5<Minus5<Zero>>, Minus10<Zero>);
+    type_eq!(<Minus50<N7> as Add<Plus50<P7>>>::Out, Zero);
+}
+
+#[test]
+#[allow(clippy::multiple_bound_locations, clippy::extra_unused_type_parameters)]
+fn large_numbers() {
+    fn add<A: NumType, B: NumType, Out: NumType>() -> i32 where A: Add<B, Out=Out> {
+        Out::new().into()
+    }
+    
+    fn sub<A: NumType, B: NumType, Out: NumType>() -> i32 where A: Sub<B, Out=Out> {
+        Out::new().into()
+    }
+    
+    fn halve<A: NumType, Out: NumType>() -> i32 where A: Halve<Out=Out> {
+        Out::new().into()
+    }
+    
+    // These need a `recursion_limit` above 200 (see the crate documentation)
+    type P200 = Plus100<Plus100<Zero>>;
+    type P250 = Plus50<P200>;
+    type N200 = <P200 as Neg>::Out;
+    
+    assert_eq!(200, P200::new().into());
+    assert_eq!(250u64, P250::new().into());
+    assert_eq!(-200i64, N200::new().into());
+    
+    assert_eq!(200, add::<Plus100<Zero>,Plus100<Zero>,_>());
+    assert_eq!(203, add::<P200,P3,_>());
+    assert_eq!(-197, add::<N200,P3,_>());
+    assert_eq!(200, sub::<P250,Plus50<Zero>,_>());
+    assert_eq!(-50, sub::<P200,P250,_>());
+    assert_eq!(100, halve::<P200,_>());
+    assert_eq!(125, halve::<P250,_>());
+    assert_eq!(-100, halve::<N200,_>());
+}
+
+#[test]
+fn constant_addition() {
+    type_eq!(<P3 as AddConst<4>>::Out, P7);
+    type_eq!(<P3 as AddConst<-4>>::Out, N1);
+    type_eq!(<N2 as AddConst<0>>::Out, N2);
+    type_eq!(<P7 as SubConst<4>>::Out, P3);
+    type_eq!(<P3 as SubConst<-4>>::Out, P7);
+    type_eq!(<Zero as SubConst<5>>::Out, N5);
+    type_eq!(<Zero as AddConst<32>>::Out, Plus10<Plus10<Plus10<P2>>>);
+    
+    // Agrees with the type-based operations
+    type_eq!(<N5 as AddConst<8>>::Out, <N5 as Add<P8>>::Out);
+    type_eq!(<P2 as AddConst<-9>>::Out, <P2 as Add<N9>>::Out);
+    type_eq!(<N1 as SubConst<6>>::Out, <N1 as Sub<P6>>::Out);
+    type_eq!(<P4 as SubConst<-3>>::Out, <P4 as Sub<N3>>::Out);
+    
+    let x: i32 = <Plus10<Zero> as SubConst<32>>::Out::new().into();
+    assert_eq!(-22, x);
+}
+
+#[test]
+fn exact_and_floor_halving() {
+    type_eq!(<Zero as HalveExact>::Out, Zero);
+    type_eq!(<P8 as HalveExact>::Out, P4);
+    type_eq!(<N2 as HalveExact>::Out, N1);
+    type_eq!(<Plus100<Zero> as HalveExact>::Out, Plus50<Zero>);
+
+    type_eq!(<P8 as HalveFloor>::Out, P4);
+    type_eq!(<P7 as HalveFloor>::Out, P3);
+    type_eq!(<P1 as HalveFloor>::Out, Zero);
+    type_eq!(<N1 as HalveFloor>::Out, N1);
+    type_eq!(<N7 as HalveFloor>::Out, N4);
+}
+
+#[test]
+fn umbrella_traits() {
+    // (N * (-4) + 2) / 2, as in the basics example, with only `Arith` bounds
+    trait Calculation1 { type Out: NumType; }
+    impl<N, Times4, Plus2> Calculation1 for N where N: Arith<N4, Prod=Times4>, Times4: Arith<P2, Sum=Plus2>, Plus2: Arith<P2> {
+        type Out = Plus2::Quot;
+    }
+    assert_eq!(<P3 as Calculation1>::Out::VALUE, -5);
+    assert_eq!(<N1 as Calculation1>::Out::VALUE, 3);
+
+    fn all<A: Arith<B> + Unary, B: NumType>() -> [i64; 6] {
+        [A::Sum::VALUE, A::Diff::VALUE, A::Prod::VALUE, A::Quot::VALUE, A::Negation::VALUE, A::AbsVal::VALUE]
+    }
+    assert_eq!(all::<N7, P2>(), [-5, -9, -14, -3, 7, 7]);
+    assert_eq!(all::<Zero, N3>(), [-3, 3, 0, 0, 0, 0]);
+    type_eq!(<N5 as Unary>::AbsVal, P5);
+}
+
+#[test]
+fn operation_aliases() {
+    type_eq!(Sum<P2, P3>, P5);
+    type_eq!(Diff<P2, P3>, N1);
+    type_eq!(Prod<P3, N2>, <P3 as Mul<N2>>::Out);
+    type_eq!(Quot<N7, P2>, N3);
+    type_eq!(Mod<N7, P2>, N1);
+    type_eq!(Negation<P4>, N4);
+    type_eq!(AbsVal<N4>, P4);
+    type_eq!(Halved<P6>, P3);
+    type_eq!(Doubled<N3>, <N3 as Mul<P2>>::Out);
+    type_eq!(Shleft<P3, P2>, Plus10<P2>);
+    type_eq!(Shright<N5, P1>, N3);
+    type_eq!(Cmp<P2, N2>, Greater);
+
+    // (1 + N) * (1 - N), as in the basics example
+    type Calculation<N> = Prod<Sum<P1, N>, Diff<P1, N>>;
+    type_eq!(Calculation<P3>, N8);
+    type_eq!(Calculation<N1>, Zero);
+}
+
+#[test]
+fn calc_macro() {
+    // (N * (-4) + 2) / 2 and (1 + N) * (1 - N), as in the basics example
+    trait Calculation1 { type Out: NumType; }
+    impl<N: NumType> Calculation1 for N where N: Mul<N4>, calc!(N * N4): Add<P2>, calc!(N * N4 + P2): Div<P2> {
+        type Out = calc!((N * N4 + P2) / P2);
+    }
+    type Calculation2<N> = calc!((P1 + N) * (P1 - N));
+
+    assert_eq!(<P3 as Calculation1>::Out::VALUE, -5);
+    assert_eq!(<N1 as Calculation1>::Out::VALUE, 3);
+    type_eq!(Calculation2<P3>, Prod<Sum<P1, P3>, Diff<P1, P3>>);
+    assert_eq!(<Calculation2<P3>>::VALUE, -8);
+    assert_eq!(<Calculation2<N1>>::VALUE, 0);
+
+    type_eq!(calc!(P2), P2);
+    type_eq!(calc!(P2 + P3 * P4), Plus10<P4>);
+    type_eq!(calc!((P2 + P3) * P4), Plus10<Plus10<Zero>>);
+    type_eq!(calc!(P9 - P3 - P2), P4);
+    type_eq!(calc!(P8 / P2 / P2), P2);
+    type_eq!(calc!(N7 % P3 * P2), N2);
+    type_eq!(calc!(((P1))), P1);
+    type_eq!(calc!(Plus10<P2> / tnum!(4) - Succ<P1>), P1);
+}
+
+#[test]
+fn static_assertions() {
+    assert_num_eq!(Sum<P3, P4>, P7);
+    assert_num_eq!(calc!(N3 * N3 - P9), Zero);
+    assert_pos!(Zero);
+    assert_pos!(AbsVal<N5>);
+    assert_lt!(N1, Zero);
+    assert_lt!(P2, Plus10<Zero>);
+
+    struct Offset<N>(N);
+    impl<N: Abs> Offset<N> {
+        assert_pos!(MAGNITUDE_IS_POSITIVE: AbsVal<N>);
+        assert_num_eq!(HALVED_DOUBLE: Halved<Doubled<P3>>, P3);
+        assert_lt!(ONE_LESS: N4, N3);
+    }
+    let _ = Offset(N1::new());
+    fn generic<A: Abs>() -> i64 { asserts::pos::<AbsVal<A>>(); AbsVal::<A>::VALUE }
+    assert_eq!(generic::<N8>(), 8);
+}
+
+#[test]
+fn exhaustive_ops() {
+    check_op!(Add, |a, b| a + b);
+    check_op!(Sub, |a, b| a - b);
+    check_op!(Mul, |a, b| a * b);
+    check_op!(Div, |a, b| a / b, [N10, N9, N8, N7, N6, N5, N4, N3, N2, N1, Zero, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10],
+                                 [N10, N9, N8, N7, N6, N5, N4, N3, N2, N1, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10]);
+    check_op!(Rem, |a, b| a % b, [N10, N9, N8, N7, N6, N5, N4, N3, N2, N1, Zero, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10],
+                                 [N10, N9, N8, N7, N6, N5, N4, N3, N2, N1, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10]);
+    check_op!(Gcd, |a, b| { let (mut x, mut y) = (a.abs(), b.abs()); while y != 0 { (x, y) = (y, x % y); } x });
+    check_op!(Shl, |a, b| a << b, [N10, N7, N1, Zero, P1, P6, P10], [Zero, P1, P2, P3]);
+    check_op!(Shr, |a, b| a >> b, [N10, N7, N1, Zero, P1, P6, P10], [Zero, P1, P2, P3, P4, P5]);
+    check_op!(Neg, |a| -a);
+    check_op!(Abs, |a| a.abs());
+    check_op!(Incr, |a| a + 1);
+    check_op!(Decr, |a| a - 1);
+    check_op!(Double, |a| a * 2);
+    check_op!(Triple, |a| a * 3);
+    check_op!(Quadruple, |a| a * 4);
+    check_op!(Halve, |a| a / 2, [N10, N8, N6, N4, N2, Zero, P2, P4, P6, P8, P10]);
+    check_op!(HalveFloor, |a| a >> 1);
+}
+
+#[test]
+fn gcd_and_simplify() {
+    type_eq!(<P4 as Gcd<P6>>::Out, P2);
+    type_eq!(<P6 as Gcd<P4>>::Out, P2);
+    type_eq!(<N9 as Gcd<N6>>::Out, P3);
+    type_eq!(<P7 as Gcd<P5>>::Out, P1);
+    type_eq!(<Zero as Gcd<N5>>::Out, P5);
+    type_eq!(<N5 as Gcd<Zero>>::Out, P5);
+    type_eq!(<Zero as Gcd<Zero>>::Out, Zero);
+
+    // already reduced
+    type_eq!(<P3 as Simplify<P4>>::OutNum, P3);
+    type_eq!(<P3 as Simplify<P4>>::OutDen, P4);
+    type_eq!(<Zero as Simplify<P1>>::OutNum, Zero);
+    type_eq!(<Zero as Simplify<P1>>::OutDen, P1);
+    // reducible
+    type_eq!(<P4 as Simplify<P8>>::OutNum, P1);
+    type_eq!(<P4 as Simplify<P8>>::OutDen, P2);
+    type_eq!(<P6 as Simplify<P3>>::OutNum, P2);
+    type_eq!(<P6 as Simplify<P3>>::OutDen, P1);
+    type_eq!(<Zero as Simplify<P5>>::OutDen, P1);
+    // negative numerator or denominator
+    type_eq!(<N6 as Simplify<P9>>::OutNum, N2);
+    type_eq!(<N6 as Simplify<P9>>::OutDen, P3);
+    type_eq!(<P6 as Simplify<N9>>::OutNum, N2);
+    type_eq!(<N6 as Simplify<N9>>::OutNum, P2);
+    type_eq!(<N6 as Simplify<N9>>::OutDen, P3);
+}
+
+#[test]
+fn rationals() {
+    use rational::{Rational, Add, Mul, Neg, Reduce};
+
+    type_eq!(<Rational<P1, P2> as Add<Rational<P1, P3>>>::Out, Rational<P5, P6>);
+    type_eq!(<Rational<P1, P2> as Add<Rational<P1, P2>>>::Out, Rational<P1, P1>);
+    type_eq!(<Rational<P1, P4> as Add<Rational<N1, P4>>>::Out, Rational<Zero, P1>);
+    type_eq!(<Rational<P2, P4> as Reduce>::Out, Rational<P1, P2>);
+    type_eq!(<Rational<P2, N4> as Reduce>::Out, Rational<N1, P2>);
+    type_eq!(<Rational<P5, P7> as Reduce>::Out, Rational<P5, P7>);
+    type_eq!(<Rational<P2, P3> as Mul<Rational<P3, P4>>>::Out, Rational<P1, P2>);
+    type_eq!(<Rational<N2, P3> as Mul<Rational<P3, N2>>>::Out, Rational<P1, P1>);
+    type_eq!(<Rational<P2, P6> as Neg>::Out, Rational<N1, P3>);
+
+    assert_eq!(Rational::<P1, P2>::to_f64(), 0.5);
+    assert_eq!(Rational::<N3, P4>::to_f64(), -0.75);
+    assert_eq!(Rational::<P3, N4>::new(), Rational::<P3, N4>::default());
+}
+
+#[test]
+fn indexed_wrappers() {
+    use std::collections::VecDeque;
+
+    indexed_wrapper! {
+        struct Bytes(Vec<u8>);
+    }
+    indexed_wrapper! {
+        #[derive(Clone)]
+        pub struct Queue(VecDeque<char>);
+    }
+    impl<N: PosType> Queue<N> {
+        fn front(&self) -> Option<char> { self.inner.front().cloned() }
+    }
+
+    let a: Bytes<Zero> = Bytes::new();
+    assert!(a.is_empty());
+    let a: Bytes<P3> = a.push(1).push(2).push(3);
+    let b: Bytes<P2> = Bytes::new().push(4).push(5);
+    let c: Bytes<P5> = a.append(b);
+    assert_eq!(c.len(), 5);
+    assert_eq!(c.inner(), &vec![1, 2, 3, 4, 5]);
+    assert_eq!(format!("{:?}", c), "[1, 2, 3, 4, 5] (5)");
+
+    assert_eq!(c.len(), c.inner().len());
+
+    let q: Queue<P1> = Queue::new().push('x');
+    let r: Queue<P2> = q.clone().append(q);
+    let s: Queue<P2> = r.append(Queue::default());
+    assert_eq!(s.front(), Some('x'));
+    assert_eq!(s.len(), s.inner().len());
+    assert_eq!(s.into_inner().into_iter().collect::<String>(), "xx");
+
+    // the length in the type is always the number of elements, also for duplicates
+    indexed_wrapper! {
+        struct Ids(std::collections::LinkedList<u8>);
+    }
+    let ids: Ids<P3> = Ids::new().push(1).push(1).push(1);
+    assert_eq!(ids.len(), ids.inner().len());
+    let ids: Ids<P6> = ids.append(Ids::new().push(1).push(2).push(1));
+    assert_eq!(ids.len(), ids.inner().len());
+}
+
+#[test]
+fn constant_bounds() {
+    fn at_least_4<T: AtLeast<4>>() -> i64 { T::VALUE }
+    fn at_most_n3<T: AtMost<-3>>() -> i64 { T::VALUE }
+    fn between<T: AtLeast<-2> + AtMost<2>>() -> i64 { T::VALUE }
+
+    assert_eq!(at_least_4::<P4>(), 4);
+    assert_eq!(at_least_4::<Plus50<Zero>>(), 50);
+    assert_eq!(at_most_n3::<N3>(), -3);
+    assert_eq!(at_most_n3::<<Plus10<P9> as Neg>::Out>(), -19);
+    assert_eq!(between::<N2>() + between::<Zero>() + between::<P2>(), 0);
+    fn at_least_32<T: AtLeast<32>>() {}
+    at_least_32::<Plus10<Plus10<Plus10<P2>>>>();
+    fn at_most_neg32<T: AtMost<-32>>() {}
+    at_most_neg32::<<Plus50<Zero> as Neg>::Out>();
+}
+
+#[test]
+fn absolute_value() {
+    assert_eq!(5, N5::new().abs_value());
+    assert_eq!(5, P5::new().abs_value());
+    assert_eq!(0, Zero::new().abs_value());
+    assert_eq!(50, Plus50::<Zero>::new().abs_value());
+    
+    type_eq!(<N5 as Abs>::Out, P5);
+    type_eq!(<P5 as Abs>::Out, P5);
+    type_eq!(<Zero as Abs>::Out, Zero);
+    
+    let m: u32 = N3::new().magnitude().into();
+    assert_eq!(3, m);
+}
+
+#[test]
+fn comparison() {
+    assert_eq!(Ordering::Less, cmp_values::<P2,P5>());
+    assert_eq!(Ordering::Greater, cmp_values::<N1,N3>());
+    assert_eq!(Ordering::Equal, cmp_values::<Zero,Zero>());
+    assert_eq!(Ordering::Less, cmp_values::<N1,P1>());
+    
+    assert!(lt::<N3,Zero>());
+    assert!(!lt::<P3,P3>());
+    assert!(le::<P3,P3>());
+    assert!(gt::<P1,N9>());
+    assert!(!gt::<N2,N2>());
+    assert!(ge::<N2,N2>());
+}
+
+#[test]
+fn type_level_comparison() {
+    type_eq!(<P2 as Compare<P5>>::Out, Less);
+    type_eq!(<N1 as Compare<N3>>::Out, Greater);
+    type_eq!(<N3 as Compare<N3>>::Out, Equal);
+    type_eq!(<Zero as Compare<N1>>::Out, Greater);
+    
+    fn less_eq<A: LessEq<B>, B>() {}
+    less_eq::<P2,P5>();
+    less_eq::<P5,P5>();
+    less_eq::<N5,N2>();
+    less_eq::<N1,Zero>();
+}
+
+#[test]
+fn bit_floor() {
+    type_eq!(<P1 as BitFloor>::Out, P1);
+    type_eq!(<P2 as BitFloor>::Out, P2);
+    type_eq!(<P3 as BitFloor>::Out, P2);
+    type_eq!(<P5 as BitFloor>::Out, P4);
+    type_eq!(<P7 as BitFloor>::Out, P4);
+    type_eq!(<P8 as BitFloor>::Out, P8);
+    type_eq!(<P9 as BitFloor>::Out, P8);
+    
+    let x: u32 = <Plus50<Plus10<P4>> as BitFloor>::Out::new().into();
+    assert_eq!(64, x);
+    let y: u32 = <Plus50<Plus10<P3>> as BitFloor>::Out::new().into();
+    assert_eq!(32, y);
+}
+
+#[test]
+fn bit_ceil() {
+    type_eq!(<P1 as BitCeil>::Out, P1);
+    type_eq!(<P2 as BitCeil>::Out, P2);
+    type_eq!(<P3 as BitCeil>::Out, P4);
+    type_eq!(<P4 as BitCeil>::Out, P4);
+    type_eq!(<P5 as BitCeil>::Out, P8);
+    type_eq!(<P8 as BitCeil>::Out, P8);
+    type_eq!(<P9 as BitCeil>::Out, Plus5<Plus10<P1>>);
+
+    let x: u32 = <Plus50<Plus10<P4>> as BitCeil>::Out::new().into();
+    assert_eq!(x, 64);
+    let y: u32 = <Plus50<Plus10<P5>> as BitCeil>::Out::new().into();
+    assert_eq!(y, 128);
+}
+
+#[test]
+fn shifts() {
+    type_eq!(<P3 as Double>::Out, P6);
+    type_eq!(<N4 as Double>::Out, N8);
+    type_eq!(<Zero as Double>::Out, Zero);
+
+    type_eq!(<P5 as Triple>::Out, Plus10<P5>);
+    type_eq!(<N3 as Triple>::Out, N9);
+    type_eq!(<Zero as Triple>::Out, Zero);
+    type_eq!(<P5 as Quadruple>::Out, Plus10<Plus10<Zero>>);
+    type_eq!(<N2 as Quadruple>::Out, N8);
+    type_eq!(<Zero as Quadruple>::Out, Zero);
+    type_eq!(<Plus10<P3> as Triple>::Out, <Plus10<P3> as Mul<P3>>::Out);
+    type N11 = <Plus10<P1> as Neg>::Out;
+    type_eq!(<N11 as Quadruple>::Out, <N11 as Mul<P4>>::Out);
+
+    type_eq!(<P3 as Shl<P2>>::Out, Plus10<P2>);
+    type_eq!(<N1 as Shl<P3>>::Out, N8);
+    type_eq!(<P5 as Shl<Zero>>::Out, P5);
+    type_eq!(<Zero as Shl<P4>>::Out, Zero);
+
+    type_eq!(<Plus10<P2> as Shr<P2>>::Out, P3);
+    type_eq!(<N8 as Shr<P3>>::Out, N1);
+    type_eq!(<N5 as Shr<Zero>>::Out, N5);
+
+    // rounding towards negative infinity
+    type_eq!(<P7 as Shr<P1>>::Out, P3);
+    type_eq!(<N5 as Shr<P1>>::Out, N3);
+    type_eq!(<N7 as Shr<P2>>::Out, N2);
+    type_eq!(<P1 as Shr<P1>>::Out, Zero);
+    type_eq!(<P9 as Shr<P5>>::Out, Zero);
+    type_eq!(<N1 as Shr<P1>>::Out, N1);
+    type_eq!(<N9 as Shr<P5>>::Out, N1);
+    for &(shifted, expected) in &[
+        (<<N9 as Shr<P1>>::Out as NumType>::VALUE, -9 >> 1),
+        (<<N9 as Shr<P2>>::Out as NumType>::VALUE, -9 >> 2),
+        (<<P9 as Shr<P2>>::Out as NumType>::VALUE, 9 >> 2),
+        (<<N6 as Shr<P2>>::Out as NumType>::VALUE, -6 >> 2),
+    ] {
+        assert_eq!(shifted, expected);
+    }
+
+    let x: i32 = <Plus50<P1> as Shl<P1>>::Out::new().into();
+    assert_eq!(x, 102);
+}
+
+#[test]
+fn parity() {
+    type_eq!(<Zero as Parity>::Out, True);
+    type_eq!(<P4 as Parity>::Out, True);
+    type_eq!(<P7 as Parity>::Out, False);
+    type_eq!(<N3 as Parity>::Out, False);
+    type_eq!(<N8 as Parity>::Out, True);
+    type_eq!(<Plus50<P1> as Parity>::Out, False);
+    
+    type_eq!(<<P4 as Parity>::Out as If<P1, N1>>::Out, P1);
+    type_eq!(<<N3 as Parity>::Out as If<P1, N1>>::Out, N1);
+}
+
+#[test]
+#[allow(clippy::multiple_bound_locations, clippy::extra_unused_type_parameters)]
+fn bounded_operations() {
+    fn bounded_add<A: NumType, B: NumType, Max, Out: NumType>() -> i32 where A: BoundedAdd<B, Max, Out=Out> {
+        Out::new().into()
+    }
+    
+    fn bounded_mul<A: NumType, B: NumType, Max, Out: NumType>() -> i32 where A: BoundedMul<B, Max, Out=Out> {
+        Out::new().into()
+    }
+    
+    assert_eq!(9, bounded_add::<P4,P5,P9,_>());
+    assert_eq!(-9, bounded_add::<N4,N5,P9,_>());
+    assert_eq!(1, bounded_add::<N4,P5,P1,_>());
+    assert_eq!(20, bounded_mul::<P4,P5,Plus10<Plus10<Zero>>,_>());
+    assert_eq!(-20, bounded_mul::<N4,P5,Plus10<Plus10<Zero>>,_>());
+}
+
+#[test]
+fn checked_addition() {
+    use checked::{CheckedAdd, CheckedType, Ok, Overflow};
+
+    type_eq!(<P4 as CheckedAdd<P3, P9>>::Out, Ok<P7>);
+    type_eq!(<P4 as CheckedAdd<P5, P9>>::Out, Ok<P9>);
+    type_eq!(<N4 as CheckedAdd<N5, Zero>>::Out, Ok<N9>);
+    type_eq!(<P5 as CheckedAdd<P5, P9>>::Out, Overflow);
+    type_eq!(<P1 as CheckedAdd<Zero, Zero>>::Out, Overflow);
+
+    type_eq!(<<<P4 as CheckedAdd<P3, P9>>::Out as CheckedType>::IsOk as If<P1, N1>>::Out, P1);
+    type_eq!(<<<P5 as CheckedAdd<P5, P9>>::Out as CheckedType>::IsOk as If<P1, N1>>::Out, N1);
+}
+
+#[test]
+#[allow(clippy::multiple_bound_locations, clippy::extra_unused_type_parameters)]
+fn operations() {
+    fn neg<A: NumType, Out: NumType>() -> i32 where A: Neg<Out=Out> {
+        Out::new().into()
+    }
+    
+    fn add<A: NumType, B: NumType, Out: NumType>() -> i32 where A: Add<B, Out=Out> {
+        Out::new().into()
+    }
+    
+    fn sub<A: NumType, B: NumType, Out: NumType>() -> i32 where A: Sub<B, Out=Out> {
+        Out::new().into()
+    }
+    
+    fn halve<A: NumType, Out: NumType>() -> i32 where A: Halve<Out=Out> {
+        Out::new().into()
+    }
+    
+    assert_eq!(-5, neg::<P5,_>());
     assert_eq!( 5, neg::<N5,_>());
     assert_eq!( 0, neg::<Zero,_>()); 
     
@@ -260,6 +3701,42 @@ fn operations() {
 }
 
 #[test]
+fn free_functions() {
+    use fns::{add, sub, mul, div, neg, halve};
+
+    assert_eq!(-5, neg::<P5,_>().into());
+    assert_eq!( 5, neg::<N5,_>().into());
+    assert_eq!( 0, neg::<Zero,_>().into());
+
+    assert_eq!( 5, add::<P2,P3,_>().into());
+    assert_eq!(-1, sub::<P2,P3,_>().into());
+    assert_eq!( 2, halve::<P4,_>().into());
+    assert_eq!(-6, mul::<P3,N2,_>().into());
+    assert_eq!( 3, div::<N7,N2,_>().into());
+
+    assert_eq!(-25, neg::<Plus5<Plus10<Plus10<Zero>>>,_>().into());
+    assert_eq!( 45, sub::<Plus50<Zero>, P5,_>().into());
+    assert_eq!( 50, halve::<Plus50<Plus50<Zero>>,_>().into());
+
+    let x: P6 = mul::<P3, P2, _>();
+    assert_eq!(x, 6);
+    assert_eq!(halve::<<P3 as Mul<P4>>::Out, _>(), 6);
+}
+
+#[test]
+fn operators() {
+    let x: P5 = P2::new() + P3::new();
+    let v: i32 = x.into();
+    assert_eq!(v, 5);
+
+    let y: i32 = (-(P9::new() - P4::new()) * P3::new() / N2::new()).into();
+    assert_eq!(y, 7);
+    let z: i32 = (Zero::new() * N5::new() + N1::new()).into();
+    assert_eq!(z, -1);
+}
+
+#[test]
+#[allow(clippy::multiple_bound_locations, clippy::extra_unused_type_parameters)]
 fn division() {
     fn div<A: NumType, B: NumType, Out: NumType>() -> i32 where A: Div<B, Out=Out> {
         Out::new().into()
@@ -289,6 +3766,83 @@ fn division() {
 }
 
 #[test]
+fn remainder() {
+    type_eq!(<P7 as Rem<P3>>::Out, P1);
+    type_eq!(<P9 as Rem<P3>>::Out, Zero);
+    type_eq!(<P2 as Rem<P5>>::Out, P2);
+    type_eq!(<Zero as Rem<N4>>::Out, Zero);
+    type_eq!(<N7 as Rem<P3>>::Out, N1);
+    type_eq!(<P7 as Rem<N3>>::Out, P1);
+    type_eq!(<N7 as Rem<N3>>::Out, N1);
+    for &(rem, expected) in &[
+        (<<Plus50<P3> as Rem<P8>>::Out as NumType>::VALUE, 53 % 8),
+        (<<N9 as Rem<P4>>::Out as NumType>::VALUE, -9 % 4),
+        (<<P8 as Rem<N5>>::Out as NumType>::VALUE, 8 % -5),
+    ] {
+        assert_eq!(rem, expected);
+    }
+}
+
+#[test]
+fn divisibility() {
+    fn aligned<N: NumType + IsDivisibleBy<P4>>() -> i64 { N::VALUE }
+
+    assert_eq!(aligned::<Zero>(), 0);
+    assert_eq!(aligned::<P4>(), 4);
+    assert_eq!(aligned::<N8>(), -8);
+    assert_eq!(aligned::<Plus50<Plus10<P4>>>(), 64);
+    fn by<A: IsDivisibleBy<D>, D: NonZero>() {}
+    by::<P9, P3>();
+    by::<P9, N9>();
+    by::<P5, P1>();
+}
+
+#[test]
+fn modular_exponentiation() {
+    type_eq!(<P3 as PowMod<P4, P5>>::Out, P1);
+    type_eq!(<P2 as PowMod<Plus10<Zero>, P7>>::Out, P2);
+    type_eq!(<P5 as PowMod<Zero, P3>>::Out, P1);
+    type_eq!(<P5 as PowMod<Zero, P1>>::Out, Zero);
+    type_eq!(<P4 as PowMod<P3, P1>>::Out, Zero);
+    type_eq!(<Zero as PowMod<P3, P4>>::Out, Zero);
+    type_eq!(<P7 as PowMod<P2, N5>>::Out, P4);
+    type_eq!(<Plus10<P9> as PowMod<Plus10<Zero>, Plus10<P3>>>::Out, P4);
+}
+
+#[test]
+#[allow(clippy::multiple_bound_locations, clippy::extra_unused_type_parameters)]
+fn long_division() {
+    fn div<A: NumType, B: NumType, Out: NumType>() -> i32 where A: Div<B, Out=Out> {
+        Out::new().into()
+    }
+    
+    type P60 = Plus10<Plus50<Zero>>;
+    type N60 = <P60 as Neg>::Out;
+    
+    // With repeated subtraction the nesting depth grew linearly with the quotient, so that
+    // already 70 / 1 overflowed the default recursion limit. Long division only needs about
+    // log2(quotient) nested steps, e.g. 7 for 80 / 1.
+    assert_eq!(60, div::<P60,P1,_>());
+    assert_eq!(30, div::<P60,P2,_>());
+    assert_eq!(20, div::<P60,P3,_>());
+    assert_eq!(15, div::<P60,P4,_>());
+    assert_eq!(-12, div::<P60,N5,_>());
+    assert_eq!(10, div::<N60,N6,_>());
+    assert_eq!(80, div::<Plus10<Plus10<P60>>,P1,_>());
+    
+    // Non-exact division rounds towards zero
+    assert_eq!(8, div::<P60,P7,_>());
+    assert_eq!(-8, div::<N60,P7,_>());
+    assert_eq!(-8, div::<P60,N7,_>());
+    assert_eq!(8, div::<N60,N7,_>());
+    assert_eq!(0, div::<P3,P7,_>());
+    assert_eq!(0, div::<N3,P7,_>());
+    assert_eq!(1, div::<P5,P3,_>());
+    assert_eq!(-1, div::<N5,P3,_>());
+}
+
+#[test]
+#[allow(clippy::multiple_bound_locations, clippy::extra_unused_type_parameters)]
 fn multiplication() {
     
     fn mul<A: NumType, B: NumType, Out: NumType>() -> i32 where A: Mul<B, Out=Out> {
@@ -322,4 +3876,468 @@ fn multiplication() {
     
     assert_eq!(25, mul::<P5,P5,_>());
     assert_eq!(25, mul::<N5,N5,_>());
+    
+    // The recursion runs over the smaller operand, whatever the order of the arguments
+    type P60 = Plus10<Plus50<Zero>>;
+    type N60 = <P60 as Neg>::Out;
+    assert_eq!(100, mul::<Plus50<Zero>,P2,_>());
+    assert_eq!(100, mul::<P2,Plus50<Zero>,_>());
+    assert_eq!(120, mul::<P60,P2,_>());
+    assert_eq!(120, mul::<P2,P60,_>());
+    assert_eq!(-120, mul::<N60,P2,_>());
+    assert_eq!(-120, mul::<P2,N60,_>());
+    assert_eq!(120, mul::<N60,N2,_>());
+    assert_eq!(120, mul::<N2,N60,_>());
+    assert_eq!(-60, mul::<P60,N1,_>());
+    assert_eq!(-60, mul::<N1,P60,_>());
+    assert_eq!(0, mul::<P60,Zero,_>());
+    assert_eq!(0, mul::<Zero,N60,_>());
+}
+
+#[test]
+fn type_equality() {
+    type_eq!(Zero, Zero);
+    type_eq!(P3, Succ<Succ<Succ<Zero>>>);
+    type_eq!(N2, Pred<Pred<Zero>>);
+    type_eq!(<P2 as Add<P3>>::Out, P5);
+    type_eq!(<N2 as Mul<P2>>::Out, N4);
+    type_eq!(Plus10<Zero>, Plus5<P5>);
+    type_eq!(<Succ<Pred<Zero>> as Normalize>::Out, Zero);
+}
+
+#[test]
+fn depth() {
+    fn depth_and_value<N: Depth>() -> (usize, i64) {
+        (N::DEPTH, N::new().into())
+    }
+    
+    assert_eq!((0, 0), depth_and_value::<Zero>());
+    assert_eq!((3, 3), depth_and_value::<P3>());
+    assert_eq!((3, -3), depth_and_value::<N3>());
+    assert_eq!((50, 50), depth_and_value::<Plus50<Zero>>());
+    assert_eq!((2, 0), depth_and_value::<Succ<Pred<Zero>>>());
+    assert_eq!((3, 1), depth_and_value::<Succ<Pred<Succ<Zero>>>>());
+    assert_eq!((11, -1), depth_and_value::<Pred<Pred<Plus5<N4>>>>());
+    
+    // The canonical form has a depth equal to the magnitude
+    assert_eq!(1, <<Succ<Pred<Succ<Zero>>> as Normalize>::Out as Depth>::DEPTH);
+}
+
+#[test]
+fn sign() {
+    type_eq!(<Zero as Sign>::Out, Neutral);
+    type_eq!(<P1 as Sign>::Out, Positive);
+    type_eq!(<Plus50<Zero> as Sign>::Out, Positive);
+    type_eq!(<N1 as Sign>::Out, Negative);
+    
+    // Blanket impls keyed on `PosType` and `NegType` would conflict because of `Zero`,
+    // but dispatching on the sign does not.
+    trait Describe { fn describe() -> &'static str; }
+    
+    trait DescribeSign<S> { fn describe_sign() -> &'static str; }
+    impl<N: PosType> DescribeSign<Positive> for N { fn describe_sign() -> &'static str { "positive" } }
+    impl<N: NegType> DescribeSign<Negative> for N { fn describe_sign() -> &'static str { "negative" } }
+    impl<N: NumType> DescribeSign<Neutral> for N { fn describe_sign() -> &'static str { "zero" } }
+    
+    impl<N: Sign> Describe for N where N: DescribeSign<N::Out> {
+        fn describe() -> &'static str { <N as DescribeSign<N::Out>>::describe_sign() }
+    }
+    
+    assert_eq!("positive", P3::describe());
+    assert_eq!("negative", N3::describe());
+    assert_eq!("zero", Zero::describe());
+}
+
+#[test]
+fn sign_dispatch_to_one_trait() {
+    // Downstream code with a pair of blanket impls of `Distance`, keyed on `PosType` and
+    // `NegType`, doesn't compile (see tests/ui/sign_overlap.rs). Keyed on the sign, the
+    // same two impls are impls of one trait for every `N`, which don't overlap.
+    trait Distance { fn distance() -> u64; }
+    
+    trait DistanceBySign<N> { fn distance() -> u64; }
+    impl<N: PosType> DistanceBySign<N> for Positive { fn distance() -> u64 { N::UVALUE } }
+    impl<N: NegType> DistanceBySign<N> for Negative { fn distance() -> u64 { N::VALUE.unsigned_abs() } }
+    impl<N: NumType> DistanceBySign<N> for Neutral { fn distance() -> u64 { 0 } }
+    
+    impl<N: Sign> Distance for N where N::Out: DistanceBySign<N> {
+        fn distance() -> u64 { <N::Out as DistanceBySign<N>>::distance() }
+    }
+    
+    assert_eq!(3, P3::distance());
+    assert_eq!(3, N3::distance());
+    assert_eq!(0, Zero::distance());
+    assert_eq!(50, <Minus50<Zero>>::distance());
+}
+
+#[test]
+fn normalization() {
+    assert_eq!(0, normalize::<Succ<Pred<Zero>>>().into());
+    assert_eq!(1, normalize::<Succ<Pred<Succ<Zero>>>>().into());
+    assert_eq!(-2, normalize::<Pred<Succ<Pred<Pred<Zero>>>>>().into());
+    
+    type_eq!(<Zero as Normalize>::Out, Zero);
+    type_eq!(<P5 as Normalize>::Out, P5);
+    type_eq!(<N5 as Normalize>::Out, N5);
+    type_eq!(<Succ<Pred<Zero>> as Normalize>::Out, Zero);
+    type_eq!(<Pred<Succ<Zero>> as Normalize>::Out, Zero);
+    type_eq!(<Succ<Succ<Pred<Zero>>> as Normalize>::Out, P1);
+    type_eq!(<Pred<Pred<Succ<Succ<Pred<Zero>>>>> as Normalize>::Out, N1);
+    type_eq!(<Plus5<Pred<Pred<Zero>>> as Normalize>::Out, P3);
+    
+    // The canonical form can be used with the arithmetic operations
+    type X = <Succ<Succ<Pred<Zero>>> as Normalize>::Out;
+    type_eq!(<X as Add<P2>>::Out, P3);
+    type_eq!(<Zero as Add<X>>::Out, P1);
+    
+    let x: P1 = normalize::<Succ<Pred<Succ<Zero>>>>();
+    assert_eq!(1, x.into());
+    let y: N2 = normalize::<Pred<Pred<Pred<Succ<Zero>>>>>();
+    assert_eq!(-2, y.into());
+    let z: Zero = normalize::<Zero>();
+    assert_eq!(0, z.into());
+}
+
+#[test]
+fn erased_numbers() {
+    let p = AnyNum::from(P2::new());
+    let n = AnyNum::from(N3::new());
+    let z = AnyNum::from(Zero::new());
+    assert_eq!((p.value(), n.value(), z.value()), (2, -3, 0));
+    assert_eq!(i64::from(AnyNum::from(Plus50::<P1>::new())), 51);
+
+    assert!(p.matches::<P2>());
+    assert!(!p.matches::<N2>() && !p.matches::<P3>() && !p.matches::<Zero>());
+    assert!(n.matches::<N3>() && !n.matches::<P3>());
+    assert!(z.matches::<Zero>() && !z.matches::<P1>());
+    assert_eq!(p.try_as::<P2>(), Some(P2::new()));
+    assert_eq!(n.try_as::<N2>(), None);
+
+    assert_eq!((p + n).value(), -1);
+    assert_eq!((p - n).value(), 5);
+    assert_eq!((p * n).value(), -6);
+    assert_eq!((n / p).value(), -1);
+    assert_eq!((n % p).value(), -1);
+    assert_eq!((-n).value(), 3);
+    assert!(n < z && z < p);
+    assert_eq!((p * n).to_string(), "-6");
+    assert!((p + AnyNum::from(P1::new())).matches::<<P2 as Add<P1>>::Out>());
+}
+
+#[test]
+fn dyn_values() {
+    let nums: Vec<Box<dyn NumValue>> = vec![Box::new(P2::new()), Box::new(N3::new()), Box::new(Zero::new())];
+    assert_eq!(nums.iter().map(|n| n.value()).sum::<i64>(), -1);
+    let signs: Vec<Ordering> = nums.iter().map(|n| n.sign()).collect();
+    assert_eq!(signs, [Ordering::Greater, Ordering::Less, Ordering::Equal]);
+    assert_eq!(NumValue::value(&Plus10::<N1>::new()), 9);
+}
+
+#[test]
+fn type_level_counter() {
+    let c = Counter::new().incr().incr().incr().incr().incr();
+    let _: Counter<P5> = c;
+    assert_eq!(c.total(), 5);
+    assert_eq!(c.decr().decr().total(), 3);
+    assert_eq!(Counter::new().decr().decr().total(), -2);
+    assert_eq!(Counter::default().incr().decr(), Counter::<Zero>::new());
+}
+
+#[test]
+fn dynamic_dispatch() {
+    fn describe(n: DynNum) -> String {
+        match n {
+            DynNum::Zero(z) => format!("zero {}", z),
+            DynNum::P16(p) => format!("max {}", p),
+            DynNum::N16(p) => format!("min {}", p),
+            other => format!("{}", other.value()),
+        }
+    }
+
+    assert_eq!(dispatch_tynum(0, describe), Some("zero 0".to_string()));
+    assert_eq!(dispatch_tynum(16, describe), Some("max 16".to_string()));
+    assert_eq!(dispatch_tynum(-16, describe), Some("min -16".to_string()));
+    assert_eq!(dispatch_tynum(-7, describe), Some("-7".to_string()));
+    assert_eq!(dispatch_tynum(17, describe), None);
+    assert_eq!(dispatch_tynum(i64::MIN, describe), None);
+
+    for n in -16..=16 {
+        assert_eq!(DynNum::try_from(n).map(|d| d.value()), Ok(n));
+    }
+    assert_eq!(DynNum::try_from(-17), Err(OutOfRange(-17)));
+    assert_eq!(DynNum::try_from(5), Ok(DynNum::P5(P5::new())));
+}
+
+#[test]
+fn list_length() {
+    use list::{Cons, Nil, Length, Concat};
+
+    type_eq!(<Nil as Length>::Out, Zero);
+    type_eq!(<Cons<N4, Nil> as Length>::Out, P1);
+    type_eq!(<Cons<P9, Cons<P9, Nil>> as Length>::Out, P2);
+    assert_num_eq!(<Cons<P9, Cons<P9, Cons<P9, Nil>>> as Length>::Out, P3);
+    type_eq!(<Cons<Zero, Cons<P1, Cons<N1, Cons<P2, Cons<N2, Nil>>>>> as Length>::Out, P5);
+
+    type Joined = <Cons<P1, Cons<P2, Nil>> as Concat<Cons<P3, Nil>>>::Out;
+    assert_eq!(<<Joined as Length>::Out as Mul<P2>>::Out::VALUE, 6);
+}
+
+#[test]
+fn list_abs_sum() {
+    use list::{Cons, Nil, AbsSum};
+
+    type_eq!(<Nil as AbsSum>::Out, Zero);
+    type_eq!(<Cons<N3, Nil> as AbsSum>::Out, P3);
+    type_eq!(<Cons<N3, Cons<P4, Nil>> as AbsSum>::Out, P7);
+    type_eq!(<Cons<P1, Cons<N1, Cons<P2, Cons<N2, Nil>>>> as AbsSum>::Out, P6);
+    type_eq!(<Cons<Zero, Cons<Zero, Nil>> as AbsSum>::Out, Zero);
+    type_eq!(<Cons<Plus50<Zero>, Cons<N9, Nil>> as AbsSum>::Out, Plus50<Plus5<P4>>);
+}
+
+#[test]
+fn list_scale_all() {
+    use list::{Cons, Nil, AbsSum, ScaleAll};
+
+    type_eq!(<Nil as ScaleAll<P3>>::Out, Nil);
+    type_eq!(<Cons<P1, Cons<P2, Nil>> as ScaleAll<P3>>::Out, Cons<P3, Cons<P6, Nil>>);
+    type_eq!(<Cons<P1, Cons<N2, Nil>> as ScaleAll<N2>>::Out, Cons<N2, Cons<P4, Nil>>);
+    type_eq!(<Cons<P5, Cons<N7, Nil>> as ScaleAll<Zero>>::Out, Cons<Zero, Cons<Zero, Nil>>);
+
+    type Scaled = <Cons<P1, Cons<P2, Cons<P3, Nil>>> as ScaleAll<P4>>::Out;
+    assert_eq!(<Scaled as AbsSum>::Out::VALUE, 24);
+    type ScaledNeg = <Cons<N1, Cons<P2, Nil>> as ScaleAll<N5>>::Out;
+    assert_eq!(<ScaledNeg as AbsSum>::Out::VALUE, 15);
+}
+
+#[test]
+fn list_concat() {
+    use list::{Cons, Nil, AbsSum, Concat};
+
+    type_eq!(<Nil as Concat<Nil>>::Out, Nil);
+    type_eq!(<Nil as Concat<Cons<P2, Nil>>>::Out, Cons<P2, Nil>);
+    type_eq!(<Cons<P1, Nil> as Concat<Nil>>::Out, Cons<P1, Nil>);
+    type_eq!(<Cons<P1, Nil> as Concat<Cons<P2, Nil>>>::Out, Cons<P1, Cons<P2, Nil>>);
+    type_eq!(<Cons<P1, Cons<N2, Nil>> as Concat<Cons<P3, Cons<N4, Nil>>>>::Out, Cons<P1, Cons<N2, Cons<P3, Cons<N4, Nil>>>>);
+
+    type Joined = <Cons<N5, Cons<P1, Nil>> as Concat<Cons<P7, Nil>>>::Out;
+    assert_eq!(<Joined as AbsSum>::Out::VALUE, 13);
+}
+
+#[test]
+fn list_reverse() {
+    use list::{Cons, Nil, Reverse};
+
+    type_eq!(<Nil as Reverse>::Out, Nil);
+    type_eq!(<Cons<P1, Nil> as Reverse>::Out, Cons<P1, Nil>);
+    type_eq!(<Cons<P1, Cons<P2, Cons<P3, Nil>>> as Reverse>::Out, Cons<P3, Cons<P2, Cons<P1, Nil>>>);
+    type_eq!(<Cons<N1, Cons<Zero, Nil>> as Reverse>::Out, Cons<Zero, Cons<N1, Nil>>);
+    type_eq!(<<Cons<P4, Cons<N5, Cons<P6, Nil>>> as Reverse>::Out as Reverse>::Out, Cons<P4, Cons<N5, Cons<P6, Nil>>>);
+}
+
+#[test]
+fn list_head_tail() {
+    use list::{Cons, Nil, Head, Tail, Concat, Length};
+
+    type L = Cons<N3, Cons<P4, Cons<Zero, Nil>>>;
+    type_eq!(<L as Head>::Out, N3);
+    type_eq!(<L as Tail>::Out, Cons<P4, Cons<Zero, Nil>>);
+    type_eq!(<<L as Tail>::Out as Head>::Out, P4);
+    type_eq!(<Cons<P1, Nil> as Tail>::Out, Nil);
+
+    type Shape = <Cons<P2, Cons<P3, Nil>> as Concat<Cons<P4, Nil>>>::Out;
+    type_eq!(<Shape as Length>::Out, P3);
+    type_eq!(<<Shape as Tail>::Out as Tail>::Out, Cons<P4, Nil>);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn list_to_vec() {
+    use list::{Cons, Nil, Concat, Reverse, to_vec};
+
+    assert_eq!(to_vec::<Nil>(), []);
+    assert_eq!(to_vec::<Cons<N3, Cons<P4, Cons<Zero, Nil>>>>(), [-3, 4, 0]);
+    type Joined = <Cons<P1, Cons<P2, Nil>> as Concat<Cons<Plus50<Zero>, Nil>>>::Out;
+    assert_eq!(to_vec::<Joined>(), [1, 2, 50]);
+    assert_eq!(to_vec::<<Joined as Reverse>::Out>(), [50, 2, 1]);
+}
+
+#[test]
+fn list_higher_order() {
+    use list::{Cons, Nil, MapList, FoldList, FilterList, Length};
+
+    type L = Cons<N3, Cons<P5, Cons<Zero, Cons<N2, Nil>>>>;
+    type_eq!(<L as MapList<NegFn>>::Out, Cons<P3, Cons<N5, Cons<Zero, Cons<P2, Nil>>>>);
+    type_eq!(<L as MapList<DoubleFn>>::Out, Cons<N6, Cons<P10, Cons<Zero, Cons<N4, Nil>>>>);
+    type_eq!(<Nil as MapList<NegFn>>::Out, Nil);
+
+    // the sum of a list and its negation is zero
+    type Both = <L as list::Concat<<L as MapList<NegFn>>::Out>>::Out;
+    type_eq!(<Both as FoldList<AddFn, Zero>>::Out, Zero);
+    type_eq!(<L as FoldList<AddFn, Zero>>::Out, Zero);
+    type_eq!(<L as FoldList<AddFn, P10>>::Out, P10);
+    type_eq!(<Nil as FoldList<MulFn, P1>>::Out, P1);
+    type_eq!(<Cons<P2, Cons<P3, Cons<P4, Nil>>> as FoldList<MulFn, P1>>::Out, P24);
+
+    type_eq!(<L as FilterList<IsPosPred>>::Out, Cons<P5, Cons<Zero, Nil>>);
+    type_eq!(<L as FilterList<IsNegPred>>::Out, Cons<N3, Cons<Zero, Cons<N2, Nil>>>);
+    type_eq!(<Nil as FilterList<IsPosPred>>::Out, Nil);
+    type_eq!(<<<L as MapList<NegFn>>::Out as FilterList<IsPosPred>>::Out as Length>::Out, P3);
+
+    // a custom predicate, selecting the even numbers
+    struct IsEven;
+    impl<A: Parity> TyFn<A> for IsEven { type Out = A::Out; }
+    type_eq!(<Cons<P1, Cons<P2, Cons<N4, Cons<N5, Nil>>>> as FilterList<IsEven>>::Out, Cons<P2, Cons<N4, Nil>>);
+}
+
+#[test]
+fn countdowns() {
+    assert!(countdown::<P3>().eq([3, 2, 1]));
+    assert_eq!(countdown::<Zero>().next(), None);
+    assert!(countdown::<P1>().eq([1]));
+    assert_eq!(countdown::<PMax>().count(), 120);
+    assert_eq!(countdown::<Plus10<P5>>().sum::<i64>(), 120);
+}
+
+#[test]
+fn min_max() {
+    type_eq!(<P3 as Min<N2>>::Out, N2);
+    type_eq!(<N2 as Min<P3>>::Out, N2);
+    type_eq!(<P3 as Max<N2>>::Out, P3);
+    type_eq!(<N2 as Max<P3>>::Out, P3);
+    type_eq!(<P4 as Min<P4>>::Out, P4);
+    type_eq!(<Zero as Max<N1>>::Out, Zero);
+}
+
+#[test]
+fn list_aggregates() {
+    use list::{Cons, Nil, SumList, ProdList, MinList, MaxList};
+
+    type L = Cons<P3, Cons<N2, Cons<P5, Cons<P1, Nil>>>>;
+    type_eq!(<L as SumList>::Out, P7);
+    type_eq!(<L as ProdList>::Out, <Plus10<Plus10<Plus10<Zero>>> as Neg>::Out);
+    assert_eq!(<L as ProdList>::Out::VALUE, -30);
+    type_eq!(<L as MinList>::Out, N2);
+    type_eq!(<L as MaxList>::Out, P5);
+
+    type_eq!(<Nil as SumList>::Out, Zero);
+    type_eq!(<Nil as ProdList>::Out, P1);
+    type_eq!(<Cons<N4, Nil> as MinList>::Out, N4);
+    type_eq!(<Cons<N4, Nil> as MaxList>::Out, N4);
+    type_eq!(<Cons<P2, Cons<Zero, Cons<P9, Nil>>> as ProdList>::Out, Zero);
+    type_eq!(<Cons<N1, Cons<N5, Cons<N3, Nil>>> as MaxList>::Out, N1);
+}
+
+#[test]
+fn areas() {
+    type_eq!(<P2 as Area<P3>>::Out, P6);
+    type_eq!(<P3 as Area<P2>>::Out, P6);
+    type_eq!(<Zero as Area<P9>>::Out, Zero);
+    type_eq!(<P1 as Area<P7>>::Out, P7);
+    type_eq!(<P2 as Volume<P3, P4>>::Out, P24);
+    type_eq!(<P5 as Volume<P5, Zero>>::Out, Zero);
+
+    fn cells<R: Area<C>, C: PosType>() -> usize { <R as Area<C>>::Out::USIZE }
+    assert_eq!(cells::<P8, P8>(), 64);
+    assert_eq!(cells::<Plus10<Zero>, P9>(), 90);
+}
+
+#[test]
+fn list_sort() {
+    use list::{Cons, Nil, SortList};
+
+    type Sorted = Cons<N3, Cons<N1, Cons<Zero, Cons<P2, Cons<P2, Cons<P5, Nil>>>>>>;
+    type A = Cons<P2, Cons<N1, Cons<P5, Cons<Zero, Cons<N3, Cons<P2, Nil>>>>>>;
+    type B = Cons<P5, Cons<P2, Cons<P2, Cons<Zero, Cons<N1, Cons<N3, Nil>>>>>>;
+    type C = Cons<Zero, Cons<P2, Cons<N3, Cons<P5, Cons<P2, Cons<N1, Nil>>>>>>;
+    type_eq!(<A as SortList>::Out, Sorted);
+    type_eq!(<B as SortList>::Out, Sorted);
+    type_eq!(<C as SortList>::Out, <A as SortList>::Out);
+    type_eq!(<Sorted as SortList>::Out, Sorted);
+    type_eq!(<Nil as SortList>::Out, Nil);
+    type_eq!(<Cons<P7, Nil> as SortList>::Out, Cons<P7, Nil>);
+
+    // eight elements, in reverse order
+    type Long = Cons<P9, Cons<P7, Cons<P5, Cons<P3, Cons<Zero, Cons<N2, Cons<N4, Cons<N9, Nil>>>>>>>>;
+    type_eq!(<Long as SortList>::Out, Cons<N9, Cons<N4, Cons<N2, Cons<Zero, Cons<P3, Cons<P5, Cons<P7, Cons<P9, Nil>>>>>>>>);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn list_sort_values() {
+    use list::{Cons, Nil, SortList, to_vec};
+
+    type A = Cons<P4, Cons<N8, Cons<P1, Cons<P4, Nil>>>>;
+    type B = Cons<P1, Cons<P4, Cons<P4, Cons<N8, Nil>>>>;
+    assert_eq!(to_vec::<<A as SortList>::Out>(), [-8, 1, 4, 4]);
+    assert_eq!(to_vec::<<A as SortList>::Out>(), to_vec::<<B as SortList>::Out>());
+    assert_same::<<A as SortList>::Out, <B as SortList>::Out>();
+}
+
+#[test]
+fn type_level_functions() {
+    type_eq!(<IncrFn as TyFn<P2>>::Out, P3);
+    type_eq!(<Zero as ApplyN<IncrFn, P5>>::Out, P5);
+    type_eq!(<P1 as ApplyN<DoubleFn, P3>>::Out, P8);
+    type_eq!(<N1 as ApplyN<DoubleFn, P4>>::Out, <Plus10<P6> as Neg>::Out);
+    type_eq!(<P4 as ApplyN<DecrFn, P6>>::Out, N2);
+    type_eq!(<P7 as ApplyN<NegFn, P3>>::Out, N7);
+    type_eq!(<P7 as ApplyN<IncrFn, Zero>>::Out, P7);
+
+    // a custom function, mapping N to 2N + 1
+    struct TwiceAndOne;
+    impl<A: Double<Out=B>, B: Incr> TyFn<A> for TwiceAndOne { type Out = B::Out; }
+    type_eq!(<Zero as ApplyN<TwiceAndOne, P3>>::Out, P7);
+}
+
+#[test]
+fn number_literals() {
+    type_eq!(tnum!(0), Zero);
+    type_eq!(tnum!(7), P7);
+    type_eq!(tnum!(-7), N7);
+    type_eq!(tnum!(64), Plus50<Plus10<P4>>);
+    type_eq!(tnum!(120), PMax);
+    struct Wrapper<N: NumType>(N);
+    let w: Wrapper<tnum!(-12)> = Wrapper(NumType::new());
+    assert_eq!(w.0, -12);
+    assert_eq!(<tnum!(99)>::VALUE, 99);
+}
+
+#[test]
+fn reification() {
+    fn seen(n: i64) -> Option<(i64, i64)> {
+        with_num!(n, 0..=32, |N| -> (i64, i64) { (N::VALUE, N::new().into()) })
+    }
+
+    assert_eq!(seen(0), Some((0, 0)));
+    assert_eq!(seen(7), Some((7, 7)));
+    assert_eq!(seen(32), Some((32, 32)));
+    assert_eq!(seen(33), None);
+    assert_eq!(seen(-1), None);
+    assert_eq!(with_num!(-16, -16..=-8, |N| -> i64 { N::VALUE }), Some(-16));
+    assert_eq!(with_num!(-7, -16..=-8, |N| -> i64 { N::VALUE }), None);
+    assert_eq!(with_num!(64, 60..=64, |N| -> i64 { N::VALUE }), Some(64));
+
+    struct Count<'a>(&'a mut u32);
+    impl<'a> NumVisitor for Count<'a> {
+        type Out = i64;
+        fn visit<N: NumType>(self) -> i64 { *self.0 += 1; N::VALUE }
+    }
+    let mut calls = 0;
+    assert_eq!(reify::<N2, P2, _>(-2, Count(&mut calls)), Some(-2));
+    assert_eq!(reify::<N2, P2, _>(3, Count(&mut calls)), None);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn runtime_dispatch() {
+    fn value<N: NumType>() -> i64 {
+        N::new().into()
+    }
+    
+    assert_eq!(Some(3), with_tynum!(3, |T| value::<T>()));
+    for n in -16..17 {
+        assert_eq!(Some(n), with_tynum!(n, |T| value::<T>()));
+    }
+    assert_eq!(None, with_tynum!(17, |T| value::<T>()));
+    assert_eq!(None, with_tynum!(-17, |T| value::<T>()));
 }
\ No newline at end of file
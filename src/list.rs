@@ -0,0 +1,237 @@
+//! Type-level lists of number types, built from `Cons` and `Nil`.
+//!
+//! ```
+//! #[macro_use] extern crate tylar;
+//! use tylar::list::{Cons, Nil, AbsSum, ScaleAll};
+//! use tylar::{P2, P4, P7, N3, N6};
+//!
+//! # fn main() {
+//! type_eq!(<Cons<N3, Cons<P4, Nil>> as AbsSum>::Out, P7);
+//! type_eq!(<Cons<N3, Nil> as ScaleAll<P2>>::Out, Cons<N6, Nil>);
+//! # }
+//! ```
+//!
+//! With `MapList`, `FoldList` and `FilterList`, type-level functions (see `TyFn`) can be
+//! applied to lists, e.g. to compute the number of elements of a tensor from its shape:
+//!
+//! ```
+//! #[macro_use] extern crate tylar;
+//! use tylar::list::{Cons, Nil, FoldList};
+//! use tylar::{MulFn, P1, P2, P3, P4, P24};
+//!
+//! # fn main() {
+//! type Shape = Cons<P2, Cons<P3, Cons<P4, Nil>>>;
+//! type_eq!(<Shape as FoldList<MulFn, P1>>::Out, P24);
+//! # }
+//! ```
+
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::{private, NumType, PosType, Zero, Succ, P1, Abs, Add, Mul, Min, Max, TyFn, BoolType, If,
+    Compare, Less, Equal, Greater};
+
+/// The empty list.
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct Nil;
+
+/// The list with the first element `H` and the remaining elements `T`.
+#[allow(dead_code)]
+#[derive(Copy,Clone,PartialEq,Eq,PartialOrd,Ord,Debug,Default,Hash)]
+pub struct Cons<H, T> {
+    phantom: PhantomData<(H, T)>
+}
+
+impl private::Sealed for Nil {}
+impl<H, T> private::Sealed for Cons<H, T> {}
+
+/// Trait implemented by all lists of number types. This trait is sealed.
+pub trait NumList: private::Sealed {
+    /// Calls `f` with the values of the elements, from the first to the last.
+    fn for_each_value<F: FnMut(i64)>(f: &mut F);
+}
+impl NumList for Nil {
+    #[inline(always)] fn for_each_value<F: FnMut(i64)>(_: &mut F) {}
+}
+impl<H: NumType, T: NumList> NumList for Cons<H, T> {
+    #[inline(always)] fn for_each_value<F: FnMut(i64)>(f: &mut F) {
+        f(H::VALUE);
+        T::for_each_value(f);
+    }
+}
+
+/// Returns the values of the elements of a list, e.g. `vec![1, -2]` for
+/// `Cons<P1, Cons<N2, Nil>>`.
+#[cfg(feature = "alloc")]
+pub fn to_vec<L: NumList>() -> Vec<i64> {
+    let mut values = Vec::new();
+    L::for_each_value(&mut |v| values.push(v));
+    values
+}
+
+/// The first element of a non-empty list.
+pub trait Head: NumList {
+    /// Result of the operation.
+    type Out: NumType;
+}
+impl<H: NumType, T: NumList> Head for Cons<H, T> { type Out = H; }
+
+/// The list without its first element, for non-empty lists.
+pub trait Tail: NumList {
+    /// Result of the operation.
+    type Out: NumList;
+}
+impl<H: NumType, T: NumList> Tail for Cons<H, T> { type Out = T; }
+
+/// The number of elements of a list, e.g. `P2` for `Cons<P9, Cons<P9, Nil>>`.
+pub trait Length: NumList {
+    /// Result of the operation, which is `Zero` for `Nil`.
+    type Out: PosType;
+}
+impl Length for Nil { type Out = Zero; }
+impl<H: NumType, T: Length> Length for Cons<H, T> { type Out = Succ<T::Out>; }
+
+/// The sum of the elements of a list, e.g. `P1` for `N3`, `P4`.
+pub trait SumList: NumList {
+    /// Result of the operation, which is `Zero` for `Nil`.
+    type Out: NumType;
+}
+impl SumList for Nil { type Out = Zero; }
+impl<H, T, S, R: NumType> SumList for Cons<H, T>
+    where T: SumList<Out=S>, H: Add<S, Out=R> { type Out = R; }
+
+/// The product of the elements of a list, e.g. `N12` for `N3`, `P4`.
+pub trait ProdList: NumList {
+    /// Result of the operation, which is `P1` for `Nil`.
+    type Out: NumType;
+}
+impl ProdList for Nil { type Out = P1; }
+impl<H, T, P, R: NumType> ProdList for Cons<H, T>
+    where T: ProdList<Out=P>, H: Mul<P, Out=R> { type Out = R; }
+
+/// The smallest element of a non-empty list, e.g. `N3` for `N3`, `P4`.
+pub trait MinList: NumList {
+    /// Result of the operation.
+    type Out: NumType;
+}
+impl<H: NumType> MinList for Cons<H, Nil> { type Out = H; }
+impl<H, H2: NumType, T, M, R: NumType> MinList for Cons<H, Cons<H2, T>>
+    where Cons<H2, T>: MinList<Out=M>, H: Min<M, Out=R> { type Out = R; }
+
+/// The largest element of a non-empty list, e.g. `P4` for `N3`, `P4`.
+pub trait MaxList: NumList {
+    /// Result of the operation.
+    type Out: NumType;
+}
+impl<H: NumType> MaxList for Cons<H, Nil> { type Out = H; }
+impl<H, H2: NumType, T, M, R: NumType> MaxList for Cons<H, Cons<H2, T>>
+    where Cons<H2, T>: MaxList<Out=M>, H: Max<M, Out=R> { type Out = R; }
+
+/// The sum of the absolute values of the elements of a list, e.g. `P7` for `N3`, `P4`.
+pub trait AbsSum: NumList {
+    /// Result of the operation, which is `Zero` for `Nil`.
+    type Out: PosType;
+}
+impl AbsSum for Nil { type Out = Zero; }
+impl<H, T, A, S, R: PosType> AbsSum for Cons<H, T>
+    where H: Abs<Out=A>, T: AbsSum<Out=S>, A: Add<S, Out=R> { type Out = R; }
+
+/// Multiplication of every element of a list by `K`, e.g. `Cons<P3, Cons<P6, Nil>>` for
+/// `Cons<P1, Cons<P2, Nil>>` and `K = P3`.
+pub trait ScaleAll<K: NumType>: NumList {
+    /// Result of the operation.
+    type Out: NumList;
+}
+impl<K: NumType> ScaleAll<K> for Nil { type Out = Nil; }
+impl<H, T, K: NumType, P: NumType, R: NumList> ScaleAll<K> for Cons<H, T>
+    where H: Mul<K, Out=P>, T: ScaleAll<K, Out=R> { type Out = Cons<P, R>; }
+
+/// Concatenation of two lists, e.g. `Cons<P1, Cons<P2, Nil>>` for `Cons<P1, Nil>` and
+/// `RHS = Cons<P2, Nil>`.
+pub trait Concat<RHS: NumList>: NumList {
+    /// Result of the operation.
+    type Out: NumList;
+}
+impl<RHS: NumList> Concat<RHS> for Nil { type Out = RHS; }
+impl<H: NumType, T, RHS: NumList, R: NumList> Concat<RHS> for Cons<H, T>
+    where T: Concat<RHS, Out=R> { type Out = Cons<H, R>; }
+
+/// Reversal of a list, e.g. `Cons<P3, Cons<P2, Cons<P1, Nil>>>` for `Cons<P1, Cons<P2, Cons<P3, Nil>>>`.
+pub trait Reverse: NumList {
+    /// Result of the operation.
+    type Out: NumList;
+}
+impl<L: NumList, R: NumList> Reverse for L where L: ReverseOnto<Nil, Out=R> { type Out = R; }
+
+/// Prepends the elements of `Self` in reverse order to `Acc`, used to implement `Reverse`.
+#[doc(hidden)]
+pub trait ReverseOnto<Acc>: NumList {
+    type Out: NumList;
+}
+impl<Acc: NumList> ReverseOnto<Acc> for Nil { type Out = Acc; }
+impl<H: NumType, T, Acc: NumList, R: NumList> ReverseOnto<Acc> for Cons<H, T>
+    where T: ReverseOnto<Cons<H, Acc>, Out=R> { type Out = R; }
+
+/// Application of the type-level function `F` to every element of a list, e.g.
+/// `Cons<N1, Cons<P2, Nil>>` for `Cons<P1, Cons<N2, Nil>>` and `F = NegFn`.
+pub trait MapList<F>: NumList {
+    /// Result of the operation.
+    type Out: NumList;
+}
+impl<F> MapList<F> for Nil { type Out = Nil; }
+impl<H: NumType, T, F, X: NumType, R: NumList> MapList<F> for Cons<H, T>
+    where F: TyFn<H, Out=X>, T: MapList<F, Out=R> { type Out = Cons<X, R>; }
+
+/// Reduction of a list with the type-level function `F` of two arguments, starting with
+/// `Init`, from the first to the last element, e.g. `P6` for `Cons<P1, Cons<P2, Nil>>`,
+/// `F = AddFn` and `Init = P3`. `F` is applied to the tuple of the accumulated value and
+/// the element.
+pub trait FoldList<F, Init>: NumList {
+    /// Result of the operation, which is `Init` for `Nil`.
+    type Out;
+}
+impl<F, Init> FoldList<F, Init> for Nil { type Out = Init; }
+impl<H: NumType, T, F, Init, X, R> FoldList<F, Init> for Cons<H, T>
+    where F: TyFn<(Init, H), Out=X>, T: FoldList<F, X, Out=R> { type Out = R; }
+
+/// The elements of a list for which the type-level predicate `P` is `True`, e.g.
+/// `Cons<P2, Nil>` for `Cons<N1, Cons<P2, Nil>>` and `P = IsPosPred`.
+pub trait FilterList<P>: NumList {
+    /// Result of the operation.
+    type Out: NumList;
+}
+impl<P> FilterList<P> for Nil { type Out = Nil; }
+impl<H: NumType, T, P, B: BoolType, R: NumList, X: NumList> FilterList<P> for Cons<H, T>
+    where P: TyFn<H, Out=B>, T: FilterList<P, Out=R>, B: If<Cons<H, R>, R, Out=X> { type Out = X; }
+
+/// Sorting of a list in ascending order, e.g. `Cons<N1, Cons<P2, Cons<P3, Nil>>>` for
+/// `Cons<P3, Cons<N1, Cons<P2, Nil>>>`, so that lists with the same elements become the
+/// same type. This is an insertion sort, which keeps equal elements in their order.
+pub trait SortList: NumList {
+    /// Result of the operation.
+    type Out: NumList;
+}
+impl SortList for Nil { type Out = Nil; }
+impl<H: NumType, T, S, R: NumList> SortList for Cons<H, T>
+    where T: SortList<Out=S>, S: Insert<H, Out=R> { type Out = R; }
+
+/// Inserts `X` into the sorted list `Self`, before the first element that is not less than
+/// `X`, used to implement `SortList`.
+#[doc(hidden)]
+pub trait Insert<X>: NumList {
+    type Out: NumList;
+}
+impl<X: NumType> Insert<X> for Nil { type Out = Cons<X, Nil>; }
+impl<X, H: NumType, T: NumList, O, R: NumList> Insert<X> for Cons<H, T>
+    where X: Compare<H, Out=O>, O: InsertStep<X, H, T, Out=R> { type Out = R; }
+
+/// Places `X` before or after `H` by the result of comparing them, used to implement `Insert`.
+#[doc(hidden)]
+pub trait InsertStep<X, H, T> {
+    type Out: NumList;
+}
+impl<X: NumType, H: NumType, T: NumList> InsertStep<X, H, T> for Less { type Out = Cons<X, Cons<H, T>>; }
+impl<X: NumType, H: NumType, T: NumList> InsertStep<X, H, T> for Equal { type Out = Cons<X, Cons<H, T>>; }
+impl<X, H: NumType, T, R: NumList> InsertStep<X, H, T> for Greater
+    where T: Insert<X, Out=R> { type Out = Cons<H, R>; }
@@ -0,0 +1,57 @@
+//! Implementations of the traits of the `num-traits` crate (with the `num-traits` feature).
+
+use core::num::ParseIntError;
+
+use num_traits::{ToPrimitive, Num, Signed, One};
+
+use super::{NumType, AnyNum, Zero, Succ, Pred, P1};
+
+macro_rules! impl_to_primitive {
+    ($([$($param:ident),*] $ty:ty),*) => {$(
+        impl<$($param),*> ToPrimitive for $ty where $ty: NumType {
+            #[inline(always)] fn to_i64(&self) -> Option<i64> { Some(<$ty as NumType>::VALUE) }
+            #[inline(always)] fn to_u64(&self) -> Option<u64> { <$ty as NumType>::VALUE.to_u64() }
+        }
+    )*}
+}
+
+impl_to_primitive!([] Zero, [N] Succ<N>, [N] Pred<N>);
+
+impl num_traits::Zero for Zero {
+    #[inline(always)] fn zero() -> Self { Zero::new() }
+    #[inline(always)] fn is_zero(&self) -> bool { true }
+}
+
+impl One for P1 {
+    #[inline(always)] fn one() -> Self { P1::new() }
+}
+
+impl ToPrimitive for AnyNum {
+    #[inline(always)] fn to_i64(&self) -> Option<i64> { Some(self.0) }
+    #[inline(always)] fn to_u64(&self) -> Option<u64> { self.0.to_u64() }
+}
+
+impl num_traits::Zero for AnyNum {
+    #[inline(always)] fn zero() -> Self { AnyNum(0) }
+    #[inline(always)] fn is_zero(&self) -> bool { self.0 == 0 }
+}
+
+impl One for AnyNum {
+    #[inline(always)] fn one() -> Self { AnyNum(1) }
+}
+
+impl Num for AnyNum {
+    type FromStrRadixErr = ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        i64::from_str_radix(s, radix).map(AnyNum)
+    }
+}
+
+impl Signed for AnyNum {
+    #[inline(always)] fn abs(&self) -> Self { AnyNum(self.0.abs()) }
+    #[inline(always)] fn abs_sub(&self, other: &Self) -> Self { AnyNum(Signed::abs_sub(&self.0, &other.0)) }
+    #[inline(always)] fn signum(&self) -> Self { AnyNum(self.0.signum()) }
+    #[inline(always)] fn is_positive(&self) -> bool { self.0 > 0 }
+    #[inline(always)] fn is_negative(&self) -> bool { self.0 < 0 }
+}
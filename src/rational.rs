@@ -0,0 +1,80 @@
+//! Type-level rational numbers, built from a numerator and a non-zero denominator.
+//!
+//! The operations reduce their results to lowest terms, with a positive denominator, so
+//! equal rationals resulting from operations are the same type:
+//!
+//! ```
+//! #[macro_use] extern crate tylar;
+//! use tylar::rational::{Rational, Add, Reduce};
+//! use tylar::{P1, P2, P3, P5, P6};
+//!
+//! # fn main() {
+//! type_eq!(<Rational<P1, P2> as Add<Rational<P1, P3>>>::Out, Rational<P5, P6>);
+//! type_eq!(<Rational<P3, P6> as Reduce>::Out, Rational<P1, P2>);
+//! assert_eq!(Rational::<P1, P2>::to_f64(), 0.5);
+//! # }
+//! ```
+
+use core::marker::PhantomData;
+
+use super::{NumType, PosType, NonZero, Mul as NumMul, Add as NumAdd, Neg as NumNeg, Simplify};
+
+/// The rational number `N` / `D`.
+#[allow(dead_code)]
+#[derive(Copy,Clone,PartialEq,Eq,Debug,Default,Hash)]
+pub struct Rational<N: NumType, D: NonZero> {
+    phantom: PhantomData<(N, D)>
+}
+
+impl<N: NumType, D: NonZero> Rational<N, D> {
+    /// Creates a new instance of this rational number type, which is zero-sized like the
+    /// number types.
+    pub const fn new() -> Self {
+        Rational { phantom: PhantomData }
+    }
+
+    /// Returns the value of this rational number as `f64`.
+    #[inline(always)]
+    pub fn to_f64() -> f64 {
+        N::VALUE as f64 / D::VALUE as f64
+    }
+}
+
+/// Reduction of a rational number to lowest terms, with a positive denominator, e.g.
+/// `Rational<P1, P2>` for `Rational<P2, P4>` and `Rational<N1, P2>` for `Rational<P2, N4>`.
+pub trait Reduce {
+    /// Result of the operation.
+    type Out;
+}
+impl<N: NumType, D: NonZero, RN: NumType, RD: PosType + NonZero> Reduce for Rational<N, D>
+    where N: Simplify<D, OutNum=RN, OutDen=RD> { type Out = Rational<RN, RD>; }
+
+/// Addition of rational numbers, reduced to lowest terms.
+pub trait Add<RHS> {
+    /// Result of the operation.
+    type Out;
+}
+impl<N1: NumType, D1: NonZero, N2: NumType, D2: NonZero, X, Y, S: NumType, P: NonZero, R> Add<Rational<N2, D2>> for Rational<N1, D1>
+    where N1: NumMul<D2, Out=X>, N2: NumMul<D1, Out=Y>, X: NumAdd<Y, Out=S>, D1: NumMul<D2, Out=P>, Rational<S, P>: Reduce<Out=R>
+{
+    type Out = R;
+}
+
+/// Multiplication of rational numbers, reduced to lowest terms.
+pub trait Mul<RHS> {
+    /// Result of the operation.
+    type Out;
+}
+impl<N1: NumType, D1: NonZero, N2: NumType, D2: NonZero, X: NumType, P: NonZero, R> Mul<Rational<N2, D2>> for Rational<N1, D1>
+    where N1: NumMul<N2, Out=X>, D1: NumMul<D2, Out=P>, Rational<X, P>: Reduce<Out=R>
+{
+    type Out = R;
+}
+
+/// Negation of rational numbers, reduced to lowest terms.
+pub trait Neg {
+    /// Result of the operation.
+    type Out;
+}
+impl<N: NumType, D: NonZero, M: NumType, R> Neg for Rational<N, D>
+    where N: NumNeg<Out=M>, Rational<M, D>: Reduce<Out=R> { type Out = R; }
@@ -0,0 +1,86 @@
+//! A ring buffer whose capacity is part of its type.
+
+use core::fmt;
+use core::iter::Chain;
+use core::marker::PhantomData;
+use core::mem;
+use core::slice;
+use alloc::vec::Vec;
+
+use super::PosType;
+
+/// A ring buffer which holds at most `Cap` elements, where `Cap` is part of the type.
+///
+/// Pushing into a full buffer overwrites the oldest element. Since the capacity is a type,
+/// buffers of different capacities can't be mixed up:
+///
+/// ```
+/// use tylar::ring::RingBuf;
+/// use tylar::P3;
+///
+/// let mut buf = RingBuf::<_, P3>::new();
+/// for i in 1..=5 {
+///     buf.push(i);
+/// }
+/// assert_eq!(buf.iter().cloned().collect::<Vec<_>>(), vec![3, 4, 5]);
+/// ```
+pub struct RingBuf<T, Cap: PosType> {
+    buf: Vec<T>,
+    start: usize, // index of the oldest element, once the buffer is full
+    p: PhantomData<Cap>
+}
+
+impl<T, Cap: PosType> RingBuf<T, Cap> {
+    /// Creates a new, empty ring buffer with room for `Cap` elements.
+    pub fn new() -> Self {
+        RingBuf { buf: Vec::with_capacity(Cap::USIZE), start: 0, p: PhantomData }
+    }
+
+    /// Appends an element, returning the oldest element if it had to be overwritten.
+    /// If `Cap` is `Zero`, the element is returned right away.
+    pub fn push(&mut self, v: T) -> Option<T> {
+        if self.buf.len() < Cap::USIZE {
+            self.buf.push(v);
+            None
+        } else if Cap::USIZE == 0 {
+            Some(v)
+        } else {
+            let old = mem::replace(&mut self.buf[self.start], v);
+            self.start = (self.start + 1) % Cap::USIZE;
+            Some(old)
+        }
+    }
+
+    /// Returns the number of elements in the buffer.
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.buf.len() }
+
+    /// Returns whether the buffer is empty.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.buf.is_empty() }
+
+    /// Returns whether the buffer is full, i.e. whether the next `push` overwrites an element.
+    #[inline(always)]
+    pub fn is_full(&self) -> bool { self.buf.len() == Cap::USIZE }
+
+    /// Returns the capacity of the buffer, i.e. the value of `Cap`.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize { Cap::USIZE }
+
+    /// Iterates over the elements from the oldest to the newest.
+    pub fn iter(&self) -> Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        let (newer, older) = self.buf.split_at(self.start);
+        older.iter().chain(newer.iter())
+    }
+}
+
+impl<T, Cap: PosType> Default for RingBuf<T, Cap> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: fmt::Debug, Cap: PosType> fmt::Debug for RingBuf<T, Cap> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()?;
+        write!(f, " ({}/{})", self.len(), Cap::USIZE)
+    }
+}
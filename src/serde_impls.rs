@@ -0,0 +1,40 @@
+//! Serialization of number types as their integer value (with the `serde` feature).
+
+use core::fmt;
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::{Error, Expected, Unexpected};
+
+use super::{NumType, Zero, Succ, Pred};
+
+/// The value that a number type expects when it is deserialized.
+struct ExpectedValue(i64);
+
+impl Expected for ExpectedValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the integer {}", self.0)
+    }
+}
+
+macro_rules! impl_serde {
+    ($([$($param:ident),*] $ty:ty),*) => {$(
+        impl<$($param),*> Serialize for $ty where $ty: NumType {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_i64(<$ty as NumType>::VALUE)
+            }
+        }
+
+        impl<'de, $($param),*> Deserialize<'de> for $ty where $ty: NumType {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = i64::deserialize(deserializer)?;
+                if value == <$ty as NumType>::VALUE {
+                    Ok(<$ty>::new())
+                } else {
+                    Err(D::Error::invalid_value(Unexpected::Signed(value), &ExpectedValue(<$ty as NumType>::VALUE)))
+                }
+            }
+        }
+    )*}
+}
+
+impl_serde!([] Zero, [N] Succ<N>, [N] Pred<N>);
@@ -0,0 +1,184 @@
+//! A stack whose depth is tracked in its type.
+
+use core::convert::TryInto;
+use core::fmt;
+use core::marker::PhantomData;
+use core::slice;
+use alloc::vec::{self, Vec};
+
+use super::{PosType, Zero, Succ, Const, ToPeano, LessEq, Sub};
+#[cfg(feature = "generic-array")]
+use super::{ArrayLen, ToUnsigned, FromTypenum};
+#[cfg(feature = "generic-array")]
+use generic_array::{ArrayLength, GenericArray};
+
+/// A LIFO stack with `N` elements, where `N` is part of the type.
+///
+/// Every `push` returns a stack with depth `Succ<N>`, and `pop` is only available on stacks
+/// with a depth of `Succ<N>`, returning the stack with depth `N`, so that popping an empty
+/// stack is a compile error:
+///
+/// ```compile_fail
+/// use tylar::stack::TStack;
+///
+/// let (stack, _) = TStack::new().push(1).pop();
+/// let (stack, _) = stack.pop();
+/// ```
+///
+/// This is the library counterpart of the `TVec` in `examples/vec.rs`, with iteration,
+/// `map`, `zip` and `split_at` in addition to `push` and `pop`.
+pub struct TStack<T, N: PosType> {
+    vec: Vec<T>, // the length is always `N`
+    p: PhantomData<N>
+}
+
+impl<T> TStack<T, Zero> {
+    /// Creates a new, empty stack.
+    pub fn new() -> Self {
+        TStack { vec: Vec::new(), p: PhantomData }
+    }
+}
+
+impl<T: Clone, N: PosType> TStack<T, N> {
+    /// Creates a stack with `N` clones of the given value.
+    ///
+    /// ```
+    /// use tylar::stack::TStack;
+    /// use tylar::P4;
+    ///
+    /// let stack = TStack::<_, P4>::replicate('x');
+    /// assert_eq!(stack.iter().collect::<String>(), "xxxx");
+    /// ```
+    pub fn replicate(value: T) -> Self {
+        TStack { vec: alloc::vec![value; N::USIZE], p: PhantomData }
+    }
+}
+
+impl<T> Default for TStack<T, Zero> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, N: PosType> TStack<T, N> {
+    /// Pushes an element on top of the stack.
+    pub fn push(self, v: T) -> TStack<T, Succ<N>> {
+        let mut vec = self.vec;
+        vec.push(v);
+        TStack { vec, p: PhantomData }
+    }
+
+    /// Returns the number of elements on the stack, i.e. the value of `N`.
+    #[inline(always)]
+    pub fn len(&self) -> usize { N::USIZE }
+
+    /// Returns whether the stack is empty, i.e. whether `N` is `Zero`.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { N::USIZE == 0 }
+
+    /// Returns an iterator over the elements, from the bottom to the top of the stack.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.vec.iter()
+    }
+
+    /// Transforms each element, from the bottom to the top, into a stack of the same depth.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> TStack<U, N> {
+        TStack { vec: self.vec.into_iter().map(f).collect(), p: PhantomData }
+    }
+
+    /// Combines the elements of two stacks of the same depth into pairs, from the bottom to
+    /// the top.
+    pub fn zip<U>(self, other: TStack<U, N>) -> TStack<(T, U), N> {
+        TStack { vec: self.vec.into_iter().zip(other.vec).collect(), p: PhantomData }
+    }
+
+    /// Splits the stack into the bottom `I` elements and the remaining `N` – `I` elements
+    /// on top of them, where `I` must not exceed `N`:
+    ///
+    /// ```
+    /// use tylar::stack::TStack;
+    /// use tylar::{P2, P3};
+    ///
+    /// let stack: TStack<_, _> = [1, 2, 3, 4, 5].into();
+    /// let (bottom, top): (TStack<_, P2>, TStack<_, P3>) = stack.split_at::<P2>();
+    /// assert_eq!(bottom.iter().sum::<i32>(), 3);
+    /// assert_eq!(*top.peek(), 5);
+    /// ```
+    pub fn split_at<I: PosType + LessEq<N>>(self) -> (TStack<T, I>, TStack<T, <N as Sub<I>>::Out>)
+        where N: Sub<I>, <N as Sub<I>>::Out: PosType
+    {
+        let mut vec = self.vec;
+        let top = vec.split_off(I::USIZE);
+        (TStack { vec, p: PhantomData }, TStack { vec: top, p: PhantomData })
+    }
+}
+
+impl<T, N: PosType> TStack<T, Succ<N>> {
+    /// Removes the top element from the stack and returns it, together with the remaining stack.
+    pub fn pop(self) -> (TStack<T, N>, T) {
+        let mut vec = self.vec;
+        let v = vec.pop().expect("the length of the stack is tracked by its type");
+        (TStack { vec, p: PhantomData }, v)
+    }
+
+    /// Returns a reference to the top element of the stack.
+    pub fn peek(&self) -> &T {
+        self.vec.last().expect("the length of the stack is tracked by its type")
+    }
+}
+
+/// Creates a stack from an array, with the last element on top.
+impl<T, const K: usize> From<[T; K]> for TStack<T, <Const<K> as ToPeano>::Peano> where Const<K>: ToPeano {
+    fn from(array: [T; K]) -> Self {
+        TStack { vec: array.into(), p: PhantomData }
+    }
+}
+
+/// Converts a stack into an array of the same length, with the bottom element first.
+impl<T, N: PosType, const K: usize> From<TStack<T, N>> for [T; K] where Const<K>: ToPeano<Peano=N> {
+    fn from(stack: TStack<T, N>) -> Self {
+        stack.vec.try_into().unwrap_or_else(|_| unreachable!("the length of the stack is tracked by its type"))
+    }
+}
+
+/// Consumes the stack, yielding its elements from the bottom to the top.
+impl<T, N: PosType> IntoIterator for TStack<T, N> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T, N: PosType> IntoIterator for &'a TStack<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter()
+    }
+}
+
+#[cfg(feature = "generic-array")]
+impl<T, N: ToUnsigned> TStack<T, N> where ArrayLen<N>: ArrayLength {
+    /// Converts the stack into a `GenericArray` of the same length, with the bottom element
+    /// first. This can't fail, because the length of the array is derived from `N`.
+    pub fn into_array(self) -> GenericArray<T, ArrayLen<N>> {
+        GenericArray::try_from_iter(self.vec).unwrap_or_else(|_| unreachable!("the length of the stack is tracked by its type"))
+    }
+}
+
+#[cfg(feature = "generic-array")]
+impl<T, N: PosType> TStack<T, N> {
+    /// Creates a stack from a `GenericArray`, with the last element on top. The depth of
+    /// the stack is derived from the length of the array.
+    pub fn from_array<U>(array: GenericArray<T, U>) -> Self where U: ArrayLength + FromTypenum<Out=N> {
+        TStack { vec: array.into_iter().collect(), p: PhantomData }
+    }
+}
+
+impl<T: fmt::Debug, N: PosType> fmt::Debug for TStack<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.vec.fmt(f)?;
+        write!(f, " ({})", N::USIZE)
+    }
+}
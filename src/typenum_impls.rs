@@ -0,0 +1,43 @@
+//! Conversions from and to the number types of the `typenum` crate (with the `typenum` feature).
+
+use typenum::{Integer, Unsigned, UTerm, UInt, B0, B1, Z0, PInt, NInt, NonZero, P1, Add1, Sum, Diff};
+use core::ops::{Add, Sub};
+
+use super::{NumType, PosType, Zero, Succ, Pred, Double, Incr, Neg};
+
+/// Conversion of number types into `typenum` integers, e.g. `N3` into `typenum::N3`.
+pub trait ToTypenum: NumType {
+    /// The equivalent `typenum` integer.
+    type Integer: Integer;
+}
+impl ToTypenum for Zero { type Integer = Z0; }
+impl<N: ToTypenum> ToTypenum for Succ<N> where N::Integer: Add<P1>, Sum<N::Integer, P1>: Integer {
+    type Integer = Sum<N::Integer, P1>;
+}
+impl<N: ToTypenum> ToTypenum for Pred<N> where N::Integer: Sub<P1>, Diff<N::Integer, P1>: Integer {
+    type Integer = Diff<N::Integer, P1>;
+}
+
+/// Conversion of positive number types into `typenum` unsigned integers, e.g. `P8` into
+/// `typenum::U8`.
+pub trait ToUnsigned: PosType {
+    /// The equivalent `typenum` unsigned integer.
+    type Unsigned: Unsigned;
+}
+impl ToUnsigned for Zero { type Unsigned = UTerm; }
+impl<N: ToUnsigned> ToUnsigned for Succ<N> where N::Unsigned: Add<B1>, Add1<N::Unsigned>: Unsigned {
+    type Unsigned = Add1<N::Unsigned>;
+}
+
+/// Conversion of `typenum` integers and unsigned integers into number types, e.g.
+/// `typenum::U8` into `P8` and `typenum::N3` into `N3`.
+pub trait FromTypenum {
+    /// The equivalent number type.
+    type Out: NumType;
+}
+impl FromTypenum for UTerm { type Out = Zero; }
+impl<U: FromTypenum> FromTypenum for UInt<U, B0> where U::Out: Double { type Out = <U::Out as Double>::Out; }
+impl<U: FromTypenum, D: Incr> FromTypenum for UInt<U, B1> where U::Out: Double<Out=D> { type Out = D::Out; }
+impl FromTypenum for Z0 { type Out = Zero; }
+impl<U: Unsigned + NonZero + FromTypenum> FromTypenum for PInt<U> { type Out = U::Out; }
+impl<U: Unsigned + NonZero + FromTypenum> FromTypenum for NInt<U> where U::Out: Neg { type Out = <U::Out as Neg>::Out; }
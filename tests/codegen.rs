@@ -0,0 +1,43 @@
+#![cfg(feature = "codegen")]
+
+extern crate tylar;
+
+use tylar::codegen::{type_path, TooLarge};
+use tylar::NumType;
+
+// Checks that the generated path is the given type, which has the given value.
+macro_rules! check {
+    ($n:expr, $($ty:tt)+) => {{
+        let expected: String = stringify!($($ty)+).split_whitespace().collect();
+        assert_eq!(type_path($n).unwrap(), expected);
+        assert_eq!(<$($ty)+ as NumType>::VALUE, $n);
+    }}
+}
+
+#[test]
+fn type_paths() {
+    check!(0, ::tylar::Zero);
+    check!(7, ::tylar::P7);
+    check!(-7, ::tylar::N7);
+    check!(10, ::tylar::Plus10<::tylar::Zero>);
+    check!(64, ::tylar::Plus50<::tylar::Plus10<::tylar::P4>>);
+    check!(-11, ::tylar::Minus10<::tylar::N1>);
+    check!(-63, ::tylar::Minus50<::tylar::Minus10<::tylar::N3>>);
+    check!(120, ::tylar::Plus100<::tylar::Plus10<::tylar::Plus10<::tylar::Zero>>>);
+    check!(-120, ::tylar::Minus100<::tylar::Minus10<::tylar::Minus10<::tylar::Zero>>>);
+}
+
+#[test]
+fn too_large() {
+    assert_eq!(type_path(121), Err(TooLarge(121)));
+    assert_eq!(type_path(-121), Err(TooLarge(-121)));
+    assert_eq!(type_path(i64::MIN), Err(TooLarge(i64::MIN)));
+    assert_eq!(TooLarge(121).to_string(), "the magnitude of 121 exceeds MAX_PRACTICAL_DEPTH (120)");
+}
+
+#[cfg(feature = "proc-macro2")]
+#[test]
+fn type_tokens() {
+    let tokens = tylar::codegen::type_tokens(-3).unwrap();
+    assert_eq!(tokens.to_string(), ":: tylar :: N3");
+}
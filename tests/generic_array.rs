@@ -0,0 +1,49 @@
+#![cfg(all(feature = "generic-array", feature = "alloc"))]
+
+extern crate generic_array;
+extern crate tylar;
+
+use generic_array::{ArrayLength, GenericArray};
+use generic_array::sequence::GenericSequence;
+use generic_array::typenum::{U0, U3};
+use tylar::stack::TStack;
+use tylar::{ArrayLen, ToArrayLength, Zero, P2, P3, P4, P8};
+
+#[test]
+fn stack_into_array() {
+    let empty: GenericArray<u8, ArrayLen<Zero>> = TStack::new().into_array();
+    assert!(empty.is_empty());
+
+    let three: GenericArray<u8, ArrayLen<P3>> = TStack::new().push(1).push(2).push(3).into_array();
+    assert_eq!(three.as_slice(), &[1, 2, 3]);
+
+    let stack = TStack::new().push(0u8).push(1).push(2).push(3).push(4).push(5).push(6).push(7);
+    let eight: GenericArray<u8, ArrayLen<P8>> = stack.into_array();
+    assert_eq!(eight.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn stack_from_array() {
+    let stack = TStack::from_array(GenericArray::<u8, U3>::from([1, 2, 3]));
+    let (stack, top): (TStack<u8, P2>, u8) = stack.pop();
+    assert_eq!(top, 3);
+    assert_eq!(stack.len(), 2);
+
+    let empty: TStack<u8, Zero> = TStack::from_array(GenericArray::<u8, U0>::from([]));
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn array_length() {
+    let four: GenericArray<u8, ArrayLen<P4>> = GenericArray::default();
+    assert_eq!(four.len(), 4);
+
+    fn filled<N: ToArrayLength>(value: u8) -> GenericArray<u8, N::Length> {
+        GenericArray::generate(|_| value)
+    }
+    assert_eq!(filled::<P4>(7).as_slice(), &[7, 7, 7, 7]);
+    assert!(filled::<Zero>(7).is_empty());
+
+    fn length<L: ArrayLength>() -> usize { L::USIZE }
+    assert_eq!(length::<<P4 as ToArrayLength>::Length>(), 4);
+}
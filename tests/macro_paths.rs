@@ -0,0 +1,18 @@
+// The exported macros must work when they are called by path, without `#[macro_use]`.
+extern crate tylar;
+
+use tylar::NumType;
+
+#[test]
+fn with_num_by_path() {
+    assert_eq!(tylar::with_num!(20, 0..=32, |N| -> i64 { N::VALUE * 2 }), Some(40));
+    assert_eq!(tylar::with_num!(-3, -5..=-1, |N| -> i64 { N::VALUE }), Some(-3));
+    assert_eq!(tylar::with_num!(7, -5..=5, |N| -> i64 { N::VALUE }), None);
+}
+
+#[test]
+fn with_tynum_by_path() {
+    assert_eq!(tylar::with_tynum!(5, |N| N::VALUE * 2), Some(10));
+    assert_eq!(tylar::with_tynum!(-16, |N| N::VALUE), Some(-16));
+    assert_eq!(tylar::with_tynum!(17, |N| N::VALUE), None);
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "macros")]
+
+#[macro_use] extern crate tylar;
+
+use tylar::{n, for_range, NumType, PosType, NegType, VariantCount, Zero, N1, P1, P9, Plus50, Plus10};
+
+struct Buffer<L: PosType>(L);
+
+#[test]
+fn literals() {
+    type_eq!(n!(0), Zero);
+    type_eq!(n!(-1), N1);
+    type_eq!(n!(9), P9);
+    type_eq!(n!(63), Plus50<Plus10<tylar::P3>>);
+    type_eq!(n!(-63), tylar::Minus50<tylar::Minus10<tylar::N3>>);
+    assert_eq!(<n!(63)>::VALUE, 63);
+    assert_eq!(<n!(-63)>::VALUE, -63);
+    assert_eq!(<n!(1_00)>::VALUE, 100);
+    assert_eq!(<n!(0x2a)>::VALUE, 42);
+    assert_eq!(<n!(12u8)>::VALUE, 12);
+    assert_eq!(<n!(-120)>::VALUE, -120);
+
+    let i: i32 = <n!(-1)>::new().into();
+    assert_eq!(i, -1);
+    let u: u64 = <n!(63)>::new().into();
+    assert_eq!(u, 63);
+}
+
+#[test]
+fn type_position() {
+    fn neg<N: NegType>() -> i64 { N::VALUE }
+    assert_eq!(neg::<n!(-17)>(), -17);
+
+    let s: Buffer<n!(77)> = Buffer(NumType::new());
+    assert_eq!(Into::<usize>::into(s.0), 77);
+    type_eq!(tylar::aliases::Sum<n!(40), n!(-41)>, N1);
+}
+
+trait Lookup {
+    const SQUARE: i64;
+    const ABS: i64;
+}
+
+for_range!(I in -12..=16 {
+    impl Lookup for n!(I) {
+        const SQUARE: i64 = i64::pow(I, 2);
+        const ABS: i64 = i64::abs(I);
+    }
+});
+
+trait Literal { const LITERAL: i64; }
+for_range!(I in -3..3 {
+    impl Literal for n!(I) { const LITERAL: i64 = I; }
+});
+
+#[test]
+fn generated_items() {
+    fn check<T: NumType + Lookup>() {
+        assert_eq!(T::SQUARE, T::VALUE * T::VALUE);
+        assert_eq!(T::ABS, T::VALUE.abs());
+    }
+    check::<n!(-12)>();
+    check::<N1>();
+    check::<Zero>();
+    check::<P9>();
+    check::<n!(16)>();
+    assert_eq!(<n!(-7) as Lookup>::ABS, 7);
+
+    fn literal<T: NumType + Literal>() { assert_eq!(T::VALUE, T::LITERAL) }
+    literal::<n!(-3)>();
+    literal::<n!(2)>();
+
+    let mut sum = 0;
+    for_range!(K in 1..=4 {
+        sum += <n!(K) as NumType>::VALUE;
+    });
+    assert_eq!(sum, 10);
+}
+
+#[derive(VariantCount)]
+enum Single { Only }
+
+#[allow(dead_code)]
+#[derive(VariantCount, Clone, Copy)]
+/// Variants with fields, attributes and discriminants.
+#[repr(u8)]
+pub(crate) enum Shape {
+    /// A doc comment.
+    Circle(f64),
+    #[allow(dead_code)]
+    Rect { w: f64, h: f64 },
+    Point = 7,
+}
+
+#[allow(dead_code)]
+#[derive(VariantCount)]
+enum Month { Jan, Feb, Mar, Apr, May, Jun, Jul, Aug, Sep, Oct, Nov }
+
+#[allow(dead_code)]
+#[derive(VariantCount)]
+enum Never {}
+
+#[test]
+fn variant_count() {
+    type_eq!(<Single as VariantCount>::Count, P1);
+    type_eq!(<Shape as VariantCount>::Count, n!(3));
+    type_eq!(<Month as VariantCount>::Count, n!(11));
+    type_eq!(<Never as VariantCount>::Count, Zero);
+    assert_eq!(Single::COUNT, 1);
+    assert_eq!(Month::COUNT, 11);
+    assert_eq!(Never::COUNT, 0);
+
+    assert_eq!(Single::Only.index(), 0);
+    assert_eq!(Shape::Circle(1.0).index(), 0);
+    assert_eq!(Shape::Rect { w: 1.0, h: 2.0 }.index(), 1);
+    assert_eq!(Shape::Point.index(), 2);
+    assert_eq!(Month::Nov.index(), 10);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn variant_count_as_length() {
+    use tylar::stack::TStack;
+
+    fn tally<E: VariantCount>(items: &[E]) -> TStack<usize, E::Count> {
+        let mut variant = 0;
+        TStack::replicate(()).map(|()| {
+            variant += 1;
+            items.iter().filter(|item| item.index() == variant - 1).count()
+        })
+    }
+
+    let counts = tally(&[Shape::Point, Shape::Circle(2.0), Shape::Point]);
+    assert_eq!(counts.iter().copied().collect::<Vec<_>>(), [1, 0, 2]);
+    assert_eq!(tally(&[Month::Feb]).len(), 11);
+}
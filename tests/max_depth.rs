@@ -0,0 +1,45 @@
+// This crate uses the default `recursion_limit` of 128, unlike the tests in the library.
+#[macro_use] extern crate tylar;
+
+use tylar::{NumType, Add, Sub, Mul, Compare, Greater, PMax, MAX_PRACTICAL_DEPTH, P1, N1};
+
+assert_num_eq!(PMax, tnum!(120));
+assert_pos!(PMax);
+assert_lt!(P1, PMax);
+
+#[test]
+fn operations_at_max_depth() {
+    assert_eq!(PMax::VALUE, MAX_PRACTICAL_DEPTH as i64);
+    let x: i64 = PMax::new().into();
+    assert_eq!(x, 120);
+    assert_eq!(<PMax as Add<N1>>::Out::VALUE, 119);
+    assert_eq!(<PMax as Sub<PMax>>::Out::VALUE, 0);
+    assert_eq!(<PMax as Mul<P1>>::Out::VALUE, 120);
+    let _: Option<<PMax as Compare<P1>>::Out> = None::<Greater>;
+}
+
+#[test]
+fn literals_at_max_depth() {
+    assert_eq!(<tnum!(120)>::VALUE, 120);
+    assert_eq!(<tnum!(-120)>::VALUE, -120);
+    tylar::assert_same::<tnum!(120), PMax>();
+}
+
+#[test]
+fn sort_at_default_limit() {
+    use tylar::list::{Cons, Nil, SortList};
+    use tylar::{P9, P7, P5, P3, Zero, N2, N4, N9};
+
+    type Long = Cons<P9, Cons<P7, Cons<P5, Cons<P3, Cons<Zero, Cons<N2, Cons<N4, Cons<N9, Nil>>>>>>>>;
+    tylar::assert_same::<<Long as SortList>::Out, Cons<N9, Cons<N4, Cons<N2, Cons<Zero, Cons<P3, Cons<P5, Cons<P7, Cons<P9, Nil>>>>>>>>>();
+}
+
+#[test]
+fn composed_addition_at_default_limit() {
+    use tylar::{P58, P60};
+
+    fn sum_minus<A: NumType + Add<B, Out=S>, B: NumType, S: Sub<A, Out=O>, O: NumType>() -> i64 { O::VALUE }
+    assert_eq!(<<<P60 as Add<P58>>::Out as Add<<P60 as tylar::Neg>::Out>>::Out as NumType>::VALUE, 58);
+    assert_eq!(<<<P60 as Add<P60>>::Out as Sub<P60>>::Out as NumType>::VALUE, 60);
+    assert_eq!(sum_minus::<P60, P60, _, _>(), 60);
+}
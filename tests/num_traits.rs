@@ -0,0 +1,41 @@
+#![cfg(feature = "num-traits")]
+
+extern crate num_traits;
+extern crate tylar;
+
+use num_traits::{ToPrimitive, Num, Signed, One};
+use tylar::{AnyNum, Zero, P1, P3, N4, Plus100};
+
+fn describe<T: ToPrimitive>(x: T) -> (Option<i64>, Option<u8>, Option<u64>, Option<f64>) {
+    (x.to_i64(), x.to_u8(), x.to_u64(), x.to_f64())
+}
+
+fn sum_of<T: Num + Copy>(xs: &[T]) -> T {
+    xs.iter().fold(T::zero(), |acc, &x| acc + x)
+}
+
+#[test]
+fn to_primitive() {
+    assert_eq!(describe(P3::new()), (Some(3), Some(3), Some(3), Some(3.0)));
+    assert_eq!(describe(N4::new()), (Some(-4), None, None, Some(-4.0)));
+    assert_eq!(describe(Zero::new()), (Some(0), Some(0), Some(0), Some(0.0)));
+    assert_eq!(describe(Plus100::<P3>::new()).1, Some(103));
+    assert_eq!(N4::new().to_u32(), None);
+}
+
+#[test]
+fn zero_and_one() {
+    assert!(num_traits::Zero::is_zero(&<Zero as num_traits::Zero>::zero()));
+    assert_eq!(<P1 as One>::one(), P1::new());
+}
+
+#[test]
+fn erased_numbers() {
+    let xs = [AnyNum::from(P3::new()), AnyNum::from(N4::new()), AnyNum::from(P1::new())];
+    assert_eq!(sum_of(&xs).value(), 0);
+    assert_eq!(xs[1].abs().value(), 4);
+    assert_eq!(xs[1].signum().value(), -1);
+    assert!(xs[0].is_positive() && xs[1].is_negative());
+    assert_eq!(AnyNum::from_str_radix("-ff", 16).map(AnyNum::value), Ok(-255));
+    assert_eq!(describe(xs[1]).0, Some(-4));
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "serde")]
+
+extern crate serde_json;
+extern crate tylar;
+
+use tylar::{P3, N2, Zero};
+
+#[test]
+fn round_trip() {
+    assert_eq!(serde_json::to_string(&P3::new()).unwrap(), "3");
+    assert_eq!(serde_json::to_string(&(N2::new(), Zero::new())).unwrap(), "[-2,0]");
+
+    let x: P3 = serde_json::from_str("3").unwrap();
+    assert_eq!(x, P3::new());
+    let (n, z): (N2, Zero) = serde_json::from_str("[-2,0]").unwrap();
+    assert_eq!((n, z), (N2::new(), Zero::new()));
+}
+
+#[test]
+fn mismatched_value() {
+    let err = serde_json::from_str::<P3>("4").unwrap_err();
+    assert_eq!(err.to_string(), "invalid value: integer `4`, expected the integer 3");
+    assert!(serde_json::from_str::<N2>("\"-2\"").is_err());
+    assert!(serde_json::from_str::<(N2, Zero)>("[-2,1]").is_err());
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "test-support")]
+
+#[macro_use] extern crate tylar;
+
+use tylar::{NumType, Abs, Neg, Incr, Mul, Same};
+
+#[test]
+fn visits_every_number() {
+    let mut seen = Vec::new();
+    for_each_tynum!(-3..=4, |T| {
+        seen.push(T::VALUE);
+    });
+    assert_eq!(seen, [-3, -2, -1, 0, 1, 2, 3, 4]);
+
+    let mut count = 0;
+    for_each_tynum!(-120..=-118, |T| { count += T::VALUE; });
+    assert_eq!(count, -357);
+    for_each_tynum!(5..=5, |_T| { count = 0; });
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn properties() {
+    fn same<A: Same<B>, B>() {}
+
+    for_each_tynum!(-8..=8, |T| {
+        assert_eq!(<T as Neg>::Out::VALUE, -T::VALUE);
+        assert_eq!(<T as Abs>::Out::VALUE, T::VALUE.abs());
+        assert_eq!(<T as Incr>::Out::VALUE, T::VALUE + 1);
+        same::<<<T as Neg>::Out as Neg>::Out, T>();
+        same::<<T as Mul<T>>::Out, <<T as Abs>::Out as Mul<<T as Abs>::Out>>::Out>();
+    });
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "typenum")]
+
+#[macro_use]
+extern crate tylar;
+extern crate typenum;
+
+use tylar::{ToTypenum, ToUnsigned, FromTypenum, NumType, Zero, P3, P8, N3, Plus50};
+use typenum::{Unsigned, Integer};
+
+fn len<U: Unsigned>() -> usize { U::USIZE }
+
+#[test]
+fn to_typenum() {
+    type_eq!(<P8 as ToUnsigned>::Unsigned, typenum::U8);
+    type_eq!(<Zero as ToUnsigned>::Unsigned, typenum::U0);
+    type_eq!(<P3 as ToTypenum>::Integer, typenum::P3);
+    type_eq!(<N3 as ToTypenum>::Integer, typenum::N3);
+    type_eq!(<Zero as ToTypenum>::Integer, typenum::Z0);
+
+    assert_eq!(len::<<P8 as ToUnsigned>::Unsigned>(), 8);
+    assert_eq!(len::<<Plus50<P3> as ToUnsigned>::Unsigned>(), 53);
+    assert_eq!(<N3 as ToTypenum>::Integer::I32, -3);
+}
+
+#[test]
+fn from_typenum() {
+    type_eq!(<typenum::U8 as FromTypenum>::Out, P8);
+    type_eq!(<typenum::U0 as FromTypenum>::Out, Zero);
+    type_eq!(<typenum::N3 as FromTypenum>::Out, N3);
+    type_eq!(<typenum::Z0 as FromTypenum>::Out, Zero);
+    assert_eq!(<typenum::U53 as FromTypenum>::Out::VALUE, 53);
+}
+
+#[test]
+fn round_trip() {
+    type_eq!(<<P8 as ToUnsigned>::Unsigned as FromTypenum>::Out, P8);
+    type_eq!(<<N3 as ToTypenum>::Integer as FromTypenum>::Out, N3);
+    type_eq!(<<Plus50<P3> as ToTypenum>::Integer as FromTypenum>::Out, Plus50<P3>);
+}
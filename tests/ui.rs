@@ -0,0 +1,13 @@
+// Programs that must be rejected by the typechecker, with the expected errors checked in
+// next to them (regenerate them with `TRYBUILD=overwrite cargo test --test ui`).
+extern crate trybuild;
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    #[cfg(feature = "alloc")]
+    t.compile_fail("tests/ui/alloc/*.rs");
+    #[cfg(feature = "macros")]
+    t.compile_fail("tests/ui/macros/*.rs");
+}
@@ -0,0 +1,10 @@
+#[macro_use] extern crate tylar;
+
+use std::collections::HashSet;
+
+// a set drops duplicates, so its number of elements can't be tracked in the type
+indexed_wrapper! {
+    struct Ids(HashSet<u8>);
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+extern crate tylar;
+
+use tylar::stack::TStack;
+use tylar::{P2, P3};
+
+fn main() {
+    let stack: TStack<_, P2> = [1, 2].into();
+    let _ = stack.split_at::<P3>();
+}
@@ -0,0 +1,8 @@
+extern crate tylar;
+
+use tylar::stack::TStack;
+
+fn main() {
+    let (stack, _) = TStack::new().push(1).pop();
+    let (_, _) = stack.pop();
+}
@@ -0,0 +1,10 @@
+extern crate tylar;
+
+use tylar::stack::TStack;
+use tylar::{P2, P3};
+
+fn main() {
+    let a: TStack<_, P3> = [1, 2, 3].into();
+    let b: TStack<_, P2> = ['a', 'b'].into();
+    let _ = a.zip(b);
+}
@@ -0,0 +1,7 @@
+extern crate tylar;
+
+use tylar::{BoundedAdd, P5, P6, P9};
+
+fn main() {
+    let _: i32 = <P5 as BoundedAdd<P6, P9>>::Out::new().into();
+}
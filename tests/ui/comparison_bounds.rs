@@ -0,0 +1,17 @@
+extern crate tylar;
+
+use tylar::{AtLeast, AtMost, LessEq, NegType, NonZero, P2, P3, N1, Zero};
+
+fn at_least_2<T: AtLeast<2>>() {}
+fn at_most_0<T: AtMost<0>>() {}
+fn less_eq<A: LessEq<B>, B>() {}
+fn non_zero<T: NonZero>() {}
+fn negative<T: NegType>() {}
+
+fn main() {
+    at_least_2::<N1>();
+    at_most_0::<P2>();
+    less_eq::<P3, P2>();
+    non_zero::<Zero>();
+    negative::<P2>();
+}
@@ -0,0 +1,7 @@
+extern crate tylar;
+
+use tylar::{NumType, Div, P3, Zero};
+
+fn main() {
+    let _: i32 = <P3 as Div<Zero>>::Out::new().into();
+}
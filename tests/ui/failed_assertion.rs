@@ -0,0 +1,10 @@
+#[macro_use] extern crate tylar;
+
+use tylar::{Sum, P3, P4, P8, N1};
+
+assert_num_eq!(Sum<P3, P4>, P8);
+
+fn main() {
+    assert_pos!(N1);
+    assert_lt!(P4, P3);
+}
@@ -0,0 +1,7 @@
+extern crate tylar;
+
+use tylar::{HalveExact, NumType, P3};
+
+fn main() {
+    let _: i32 = <P3 as HalveExact>::Out::new().into();
+}
@@ -0,0 +1,7 @@
+extern crate tylar;
+
+use tylar::{Halve, P3};
+
+fn main() {
+    let _: i32 = <P3 as Halve>::Out::new().into();
+}
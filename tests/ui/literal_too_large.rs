@@ -0,0 +1,8 @@
+#[macro_use] extern crate tylar;
+
+use tylar::NumType;
+
+fn main() {
+    // literals beyond `MAX_PRACTICAL_DEPTH` are rejected
+    let _ = <tnum!(200)>::VALUE;
+}
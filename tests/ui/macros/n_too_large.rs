@@ -0,0 +1,10 @@
+extern crate tylar;
+
+use tylar::{n, NumType};
+
+fn main() {
+    // literals beyond `MAX_PRACTICAL_DEPTH` are rejected
+    let _ = <n!(1234)>::VALUE;
+    let _ = <n!(-121)>::VALUE;
+    let _ = <n!(x)>::VALUE;
+}
@@ -0,0 +1,11 @@
+extern crate tylar;
+
+use tylar::VariantCount;
+
+#[derive(VariantCount)]
+struct NotAnEnum(u8);
+
+#[derive(VariantCount)]
+enum Generic<T> { Some(T), None }
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![recursion_limit = "512"]
+extern crate tylar;
+
+use tylar::{Plus100, Plus50, Plus10, Zero};
+
+fn main() {
+    // 260 doesn't fit into `u8`
+    let x: u8 = <Plus100<Plus100<Plus50<Plus10<Zero>>>>>::new().into();
+    let _ = x;
+}
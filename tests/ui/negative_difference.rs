@@ -0,0 +1,11 @@
+extern crate tylar;
+
+use tylar::{PosType, Sub, P2, P3};
+
+fn len<N: PosType>() -> usize {
+    N::new().into()
+}
+
+fn main() {
+    len::<<P2 as Sub<P3>>::Out>();
+}
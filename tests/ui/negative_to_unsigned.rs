@@ -0,0 +1,10 @@
+extern crate tylar;
+
+use tylar::{N1, N5};
+
+fn main() {
+    // negative numbers only convert into signed integers
+    let _: u32 = N1::new().into();
+    let _: usize = N5::new().into();
+    let _: u64 = N1::new().into();
+}
@@ -0,0 +1,11 @@
+extern crate tylar;
+
+use tylar::{IsDivisibleBy, NonZero, P3, P4, P6, N9};
+
+fn divisible<A: IsDivisibleBy<D>, D: NonZero>() {}
+
+fn main() {
+    divisible::<P6, P4>();
+    divisible::<N9, P4>();
+    divisible::<P4, P3>();
+}
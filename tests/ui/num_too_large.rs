@@ -0,0 +1,8 @@
+#[macro_use] extern crate tylar;
+
+use tylar::NumType;
+
+fn main() {
+    // `num!` has the same limit as `tnum!`, also for negative literals
+    let _ = <num!(-121)>::VALUE;
+}
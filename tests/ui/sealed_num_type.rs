@@ -0,0 +1,17 @@
+extern crate tylar;
+
+// a type with all supertraits and items of `NumType`, which is still rejected, since it
+// can't implement the private `Sealed` supertrait
+struct Fake;
+
+macro_rules! from_fake {
+    ($($t:ty = $v:expr),+) => { $(impl From<Fake> for $t { fn from(_: Fake) -> $t { $v } })+ };
+}
+from_fake!(i128 = 0, i64 = 0, i32 = 0, isize = 0, f64 = 0.0, f32 = 0.0);
+
+impl tylar::NumType for Fake {
+    const VALUE: i64 = 0;
+    fn new() -> Self { Fake }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+extern crate tylar;
+
+use tylar::{PosType, NegType};
+
+// `Zero` is both a `PosType` and a `NegType`, so these impls overlap
+// (dispatch on `Sign::Out` instead, see `Sign`).
+trait Distance { fn distance() -> u64; }
+impl<N: PosType> Distance for N { fn distance() -> u64 { N::VALUE as u64 } }
+impl<N: NegType> Distance for N { fn distance() -> u64 { N::VALUE.unsigned_abs() } }
+
+fn main() {}
@@ -0,0 +1,8 @@
+extern crate tylar;
+
+use tylar::{NumType, Simplify, P4, Zero};
+
+fn main() {
+    // a fraction needs a non-zero denominator
+    let _ = <P4 as Simplify<Zero>>::OutNum::VALUE;
+}
@@ -0,0 +1,10 @@
+extern crate tylar;
+
+use std::num::NonZeroU64;
+use tylar::Zero;
+
+fn main() {
+    // `Zero` can't be converted into a non-zero integer
+    let x: NonZeroU64 = Zero::new().into();
+    let _ = x;
+}
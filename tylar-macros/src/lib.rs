@@ -0,0 +1,302 @@
+//! Procedural macros for tylar, which are re-exported by `tylar` with the `macros` feature.
+//! Use them through `tylar` instead of depending on this crate directly.
+
+extern crate proc_macro;
+
+use std::iter::Peekable;
+
+use proc_macro::{token_stream, Delimiter, Group, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+// Same as `tylar::MAX_PRACTICAL_DEPTH`, which can't be used here, since `tylar` depends on
+// this crate.
+const MAX_PRACTICAL_DEPTH: u64 = 120;
+
+/// The number type for any integer literal, e.g. `n!(1_000)` or `n!(-63)`, in type position.
+///
+/// Literals may contain `_` separators and an optional minus sign. The result is built
+/// from the building blocks `Plus100`, `Plus50`, `Plus10` and `P1`..`P9` (or `Minus100` to
+/// `N9` for negative numbers), so it stays readable in error messages. The
+/// magnitude must be at most `MAX_PRACTICAL_DEPTH`, otherwise the macro reports an error,
+/// because larger numbers would run into the default recursion limit.
+#[proc_macro]
+pub fn n(input: TokenStream) -> TokenStream {
+    match parse(input).and_then(|(n, span)| expand(n).map_err(|msg| (msg, span))) {
+        Ok(tokens) => tokens,
+        Err((msg, span)) => compile_error(&msg, span),
+    }
+}
+
+type Tokens = Peekable<token_stream::IntoIter>;
+type Error = (String, Span);
+
+/// Repeats items for every integer in a range, e.g. to implement a trait for a range of
+/// number types. Every occurrence of the given identifier in the items is replaced by the
+/// integer literal, which can be turned into a number type with `n!`:
+///
+/// ```ignore
+/// for_range!(N in 0..=16 {
+///     impl Lookup for n!(N) { const VALUE: u32 = N * N; }
+/// });
+/// ```
+///
+/// The range can be exclusive (`lo..hi`) or inclusive (`lo..=hi`), and the bounds can be
+/// negative. Since this generates a copy of the items for every integer, the range must not
+/// contain more than 1000 integers.
+#[proc_macro]
+pub fn for_range(input: TokenStream) -> TokenStream {
+    match parse_for_range(input) {
+        Ok((name, lo, hi, body)) => (lo..=hi).flat_map(|i| substitute(body.clone(), &name, i)).collect(),
+        Err((msg, span)) => compile_error(&msg, span),
+    }
+}
+
+// Parses `name in lo..hi { body }` or `name in lo..=hi { body }`.
+fn parse_for_range(input: TokenStream) -> Result<(String, i128, i128, TokenStream), Error> {
+    let mut tokens = input.into_iter().peekable();
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        other => return Err(expected("an identifier", other)),
+    };
+    match tokens.next() {
+        Some(TokenTree::Ident(ref ident)) if ident.to_string() == "in" => {}
+        other => return Err(expected("`in`", other)),
+    }
+    let (lo, _) = parse_int(&mut tokens)?;
+    for _ in 0..2 {
+        match tokens.next() {
+            Some(TokenTree::Punct(ref p)) if p.as_char() == '.' => {}
+            other => return Err(expected("`..` or `..=`", other)),
+        }
+    }
+    let inclusive = match tokens.peek() {
+        Some(TokenTree::Punct(p)) if p.as_char() == '=' => { tokens.next(); true }
+        _ => false,
+    };
+    let (hi, span) = parse_int(&mut tokens)?;
+    let hi = if inclusive { hi } else { hi - 1 };
+    let body = match tokens.next() {
+        Some(TokenTree::Group(ref g)) if g.delimiter() == Delimiter::Brace => g.stream(),
+        other => return Err(expected("the items in braces", other)),
+    };
+    if let Some(extra) = tokens.next() {
+        return Err((String::from("unexpected tokens after the items"), extra.span()));
+    }
+    if hi - lo >= 1000 {
+        return Err((format!("the range {}..={} contains more than 1000 integers", lo, hi), span));
+    }
+    Ok((name, lo, hi, body))
+}
+
+fn expected(what: &str, found: Option<TokenTree>) -> Error {
+    let span = found.map_or_else(Span::call_site, |t| t.span());
+    (format!("expected {}", what), span)
+}
+
+// Replaces every occurrence of the identifier `name` with the integer `i`.
+fn substitute(body: TokenStream, name: &str, i: i128) -> TokenStream {
+    body.into_iter().flat_map(|tree| -> Vec<TokenTree> {
+        match tree {
+            TokenTree::Ident(ref ident) if ident.to_string() == name => {
+                let mut lit = Literal::i128_unsuffixed(i.abs());
+                lit.set_span(ident.span());
+                if i < 0 {
+                    // in an invisible group, so that e.g. `N.abs()` applies to the negative number
+                    let mut minus = Punct::new('-', Spacing::Alone);
+                    minus.set_span(ident.span());
+                    let stream: TokenStream = vec![TokenTree::from(minus), lit.into()].into_iter().collect();
+                    let mut group = Group::new(Delimiter::None, stream);
+                    group.set_span(ident.span());
+                    vec![group.into()]
+                } else {
+                    vec![lit.into()]
+                }
+            }
+            TokenTree::Group(g) => {
+                let mut group = Group::new(g.delimiter(), substitute(g.stream(), name, i));
+                group.set_span(g.span());
+                vec![group.into()]
+            }
+            other => vec![other],
+        }
+    }).collect()
+}
+
+/// Implements `tylar::VariantCount` for an enum, with the variants counted and indexed in
+/// order of declaration:
+///
+/// ```ignore
+/// #[derive(VariantCount)]
+/// enum Suit { Clubs, Diamonds, Hearts, Spades }
+///
+/// let tally: TStack<u32, <Suit as VariantCount>::Count> = TStack::replicate(0);
+/// ```
+///
+/// Generic enums and enums with more than `MAX_PRACTICAL_DEPTH` variants are not supported.
+#[proc_macro_derive(VariantCount)]
+pub fn derive_variant_count(input: TokenStream) -> TokenStream {
+    match parse_enum(input) {
+        Ok((name, variants)) => {
+            let count = expand(variants.len() as i128).expect("checked by `parse_enum`");
+            let arms: String = variants.iter().enumerate()
+                .map(|(i, v)| format!("{}::{} {{ .. }} => {},", name, v, i))
+                .collect();
+            format!("impl ::tylar::VariantCount for {} {{ \
+                         type Count = {}; \
+                         #[inline] fn index(&self) -> usize {{ match *self {{ {} }} }} \
+                     }}", name, count, arms)
+                .parse().expect("impls of `VariantCount` are valid tokens")
+        }
+        Err((msg, span)) => compile_error(&msg, span),
+    }
+}
+
+// Parses an enum definition into its name and the names of its variants.
+fn parse_enum(input: TokenStream) -> Result<(String, Vec<String>), Error> {
+    let mut tokens = input.into_iter().peekable();
+    skip_attributes(&mut tokens);
+    // visibility, e.g. `pub` or `pub(crate)`
+    if let Some(TokenTree::Ident(ident)) = tokens.peek() {
+        if ident.to_string() == "pub" {
+            tokens.next();
+            if let Some(TokenTree::Group(g)) = tokens.peek() {
+                if g.delimiter() == Delimiter::Parenthesis {
+                    tokens.next();
+                }
+            }
+        }
+    }
+    match tokens.next() {
+        Some(TokenTree::Ident(ref ident)) if ident.to_string() == "enum" => {}
+        other => return Err(("`VariantCount` can only be derived for enums".into(),
+                             other.map_or_else(Span::call_site, |t| t.span()))),
+    }
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => ident,
+        other => return Err(expected("the name of the enum", other)),
+    };
+    let body = loop {
+        match tokens.next() {
+            Some(TokenTree::Punct(ref p)) if p.as_char() == '<' =>
+                return Err(("`VariantCount` can't be derived for generic enums".into(), p.span())),
+            Some(TokenTree::Group(ref g)) if g.delimiter() == Delimiter::Brace => break g.stream(),
+            Some(_) => {}
+            None => return Err(expected("the variants in braces", None)),
+        }
+    };
+    let mut variants = Vec::new();
+    let mut body = body.into_iter().peekable();
+    loop {
+        skip_attributes(&mut body);
+        match body.next() {
+            Some(TokenTree::Ident(ident)) => variants.push(ident.to_string()),
+            None => break,
+            other => return Err(expected("a variant", other)),
+        }
+        // skip fields and discriminant up to the next variant
+        loop {
+            match body.next() {
+                Some(TokenTree::Punct(ref p)) if p.as_char() == ',' => break,
+                Some(_) => {}
+                None => break,
+            }
+        }
+    }
+    if variants.len() as u64 > MAX_PRACTICAL_DEPTH {
+        return Err((format!("enums with more than MAX_PRACTICAL_DEPTH ({}) variants are not supported",
+                            MAX_PRACTICAL_DEPTH), name.span()));
+    }
+    Ok((name.to_string(), variants))
+}
+
+// Skips outer attributes, including doc comments, which are passed as `#[doc = "..."]`.
+fn skip_attributes(tokens: &mut Tokens) {
+    while let Some(TokenTree::Punct(p)) = tokens.peek() {
+        if p.as_char() != '#' {
+            break;
+        }
+        tokens.next();
+        tokens.next();
+    }
+}
+
+// Parses an integer literal, which must be all of the input.
+fn parse(input: TokenStream) -> Result<(i128, Span), Error> {
+    let mut tokens = input.into_iter().peekable();
+    let n = parse_int(&mut tokens)?;
+    match tokens.next() {
+        Some(extra) => Err((String::from("unexpected tokens after the integer literal"), extra.span())),
+        None => Ok(n),
+    }
+}
+
+// Parses an optional minus sign followed by an integer literal.
+fn parse_int(tokens: &mut Tokens) -> Result<(i128, Span), Error> {
+    // literals passed through `macro_rules!` may be wrapped in an invisible group
+    if let Some(TokenTree::Group(g)) = tokens.peek() {
+        if g.delimiter() == Delimiter::None {
+            let inner = g.stream();
+            tokens.next();
+            return parse(inner);
+        }
+    }
+    let mut negative = false;
+    if let Some(TokenTree::Punct(p)) = tokens.peek() {
+        if p.as_char() == '-' {
+            negative = true;
+            tokens.next();
+        }
+    }
+    let lit = match tokens.next() {
+        Some(TokenTree::Literal(lit)) => lit,
+        Some(other) => return Err((String::from("expected an integer literal"), other.span())),
+        None => return Err((String::from("expected an integer literal"), Span::call_site())),
+    };
+    let text: String = lit.to_string().chars().filter(|&c| c != '_').collect();
+    // a type suffix starts with `i` or `u`, which are not hexadecimal digits
+    let digits = match text.find(['i', 'u']) {
+        Some(i) => &text[..i],
+        None => &text[..],
+    };
+    let value = match digits.get(..2) {
+        Some("0x") => i128::from_str_radix(&digits[2..], 16),
+        Some("0o") => i128::from_str_radix(&digits[2..], 8),
+        Some("0b") => i128::from_str_radix(&digits[2..], 2),
+        _ => digits.parse(),
+    };
+    match value {
+        Ok(v) => Ok((if negative { -v } else { v }, lit.span())),
+        Err(_) => Err((format!("`{}` is not an integer literal", lit), lit.span())),
+    }
+}
+
+// Builds the number type, see `tylar::codegen::type_path` for the layout of positive numbers.
+// Negative numbers use the `Minus` building blocks in the same way.
+fn expand(n: i128) -> Result<TokenStream, String> {
+    if n.unsigned_abs() > MAX_PRACTICAL_DEPTH as u128 {
+        return Err(format!(
+            "the magnitude of {} exceeds MAX_PRACTICAL_DEPTH ({}), so it would run into the default recursion limit",
+            n, MAX_PRACTICAL_DEPTH));
+    }
+    let (block, digit) = if n < 0 { ("Minus", 'N') } else { ("Plus", 'P') };
+    let mut rest = n.unsigned_abs() as u64;
+    let mut path = String::new();
+    let mut depth = 0;
+    for &step in &[100, 50, 10] {
+        while rest >= step {
+            path.push_str(&format!("::tylar::{}{}<", block, step));
+            depth += 1;
+            rest -= step;
+        }
+    }
+    match rest {
+        0 => path.push_str("::tylar::Zero"),
+        r => path.push_str(&format!("::tylar::{}{}", digit, r)),
+    }
+    path.push_str(&">".repeat(depth));
+    Ok(path.parse().expect("number type paths are valid tokens"))
+}
+
+fn compile_error(msg: &str, span: Span) -> TokenStream {
+    let tokens: TokenStream = format!("compile_error! {{ {:?} }}", msg).parse().expect("valid tokens");
+    tokens.into_iter().map(|mut t| { t.set_span(span); t }).collect()
+}